@@ -15,23 +15,24 @@
 // You should have received a copy of the GNU Affero General Public License along with rs. If not,
 // see <https://www.gnu.org/licenses/>.
 
+use core::time::Duration;
 use std::{
-    io::{Result, Write},
+    io::{Read, Result, Write},
     path::{Path, PathBuf},
+    time::SystemTime,
 };
 
 use getargs::{Arg, Opt, Options};
+use time::{macros::format_description, Date, PrimitiveDateTime, Time, UtcOffset};
 
 use crate::{
-    display::HasColor,
-    sort::{HoistType, SortType},
+    completions::Shell,
+    display::{ColumnType, HasColor, IndicatorStyle, TabularFormat, TimeStyle},
+    layout::{self, LayoutType},
+    sort::{GroupType, HoistExtension, HoistType, SortType, TimeField},
+    EntryKind,
 };
 
-/// An option to be displayed in the help listing.
-type HelpOption<'a> = (Option<char>, &'a str, &'a str, Option<HelpOptionValues<'a>>);
-/// A list of values and their default.
-type HelpOptionValues<'a> = (&'a str, &'a [&'a str]);
-
 /// The application's command-line arguments.
 #[expect(clippy::struct_excessive_bools, reason = "a lot of command-line arguments are flags")]
 #[non_exhaustive]
@@ -42,29 +43,146 @@ pub struct Arguments {
 
     /// Whether to display hidden entries.
     pub show_hidden: bool,
+    /// Whether to display only hidden entries.
+    pub only_hidden: bool,
+    /// Whether to hide editor backup entries, which end in a tilde.
+    pub hide_backups: bool,
+    /// Whether to hide additional hard links to files that have already been listed.
+    pub dedup_hardlinks: bool,
+    /// The time after which entries must have been modified to be displayed, if any.
+    pub newer_than: Option<SystemTime>,
+    /// The time before which entries must have been modified to be displayed, if any.
+    pub older_than: Option<SystemTime>,
+    /// The size in bytes that entries must be at least as large as to be displayed, if any.
+    pub min_size: Option<u64>,
+    /// The size in bytes that entries must be at most as large as to be displayed, if any.
+    pub max_size: Option<u64>,
+    /// Whether to hide directories when filtering by size, rather than always displaying them.
+    pub size_filter_dirs: bool,
+    /// The kinds of entries to display, if not every kind.
+    pub entry_kinds: Option<Box<[EntryKind]>>,
     /// Whether to display file sizes.
     pub show_sizes: bool,
+    /// Whether to display the sizes of the targets of symbolic links, rather than of the links themselves.
+    pub symlink_target_size: bool,
+    /// Whether to display the number of entries within each directory.
+    pub show_child_count: bool,
+    /// Whether to highlight the sizes of sparse files.
+    pub mark_sparse: bool,
     /// Whether to display file modification date.
     pub show_modified: bool,
+    /// Whether to color displayed timestamps by their age, from bright for recent entries to dim for old entries.
+    pub time_scale: bool,
     /// Whether to display file permissions.
     pub show_permissions: bool,
     /// Whether to display file owners.
     pub show_owner: bool,
+    /// Whether to display file content types, as guessed from their extensions.
+    pub show_mime: bool,
+    /// The displayed columns, in the order that they're displayed.
+    pub columns: Box<[ColumnType]>,
     /// Whether to display resolved symbolic links.
     pub show_symlinks: bool,
+    /// Whether to display broken symbolic links like any other symbolic link, rather than highlighting them.
+    pub plain_broken_symlinks: bool,
+    /// Whether to display only the names of symbolic links, without their targets, even if they are being resolved.
+    pub compact_symlinks: bool,
+    /// Whether to display each entry's full absolute path rather than only its name.
+    pub full_path: bool,
+    /// The absolute base directory that entry paths are displayed relative to, if any.
+    pub relative_to: Option<Box<Path>>,
+    /// The maximum number of characters to display of each entry's name, if any.
+    pub truncate_names: Option<usize>,
+    /// The maximum number of entries to display in each listing, if any.
+    pub max_entries: Option<usize>,
+    /// Whether to wrap each displayed name in double quotes.
+    pub quote_names: bool,
+    /// Whether to escape backslashes and non-printable characters within displayed names.
+    pub escape_names: bool,
+    /// The style of the indicators displayed after entry names.
+    pub indicator_style: IndicatorStyle,
+    /// Whether to omit the trailing separator from directory names, regardless of the indicator style.
+    pub no_dir_slash: bool,
+    /// Whether to omit the trailing asterisk from executable names, distinguishing them only by their color.
+    pub no_exec_suffix: bool,
+    /// Whether to display a summary of the listed entries.
+    pub show_summary: bool,
+    /// Whether to display how recently the listed entries were modified within their summary.
+    pub summary_ages: bool,
+    /// Whether to display a summary of the status of the git repository containing each listed directory.
+    pub git_status_summary: bool,
+    /// Whether to display the total size of the files listed across every path.
+    pub grand_total: bool,
+    /// Whether to display each listed directory's own detail columns within its header.
+    pub header_details: bool,
+    /// Whether to display a header even when listing a single directory.
+    pub always_header: bool,
+    /// Whether to display the number of entries listed beneath each directory's header.
+    pub header_counts: bool,
+
+    /// Whether to list subdirectories recursively.
+    pub recursive: bool,
+    /// Whether to list every directory at one depth before any directory deeper within it when listing recursively.
+    pub breadth_first: bool,
+    /// Whether to display subdirectories recursively as a tree.
+    pub tree: bool,
+    /// Whether to descend into symbolic links to directories when listing recursively.
+    pub follow_symlinks: bool,
+    /// Whether to display listed symbolic links to directories by their resolved paths.
+    pub dereference_args: bool,
+    /// Whether to display the entries of every given path as a single listing.
+    pub merge: bool,
+    /// Whether to re-display the listing whenever the listed directories change.
+    pub watch: bool,
 
-    /// The method to use to sort the displayed entries.
-    pub sort_function: SortType,
+    /// The methods to use to sort the displayed entries, in order of precedence.
+    pub sort_functions: Box<[SortType]>,
     /// Whether to reverse the displayed sorting order.
     pub sort_reversed: bool,
+    /// The timestamp to display and to sort by when sorting by time.
+    pub time_field: TimeField,
+    /// The style that displayed timestamps are formatted in.
+    pub time_style: TimeStyle,
 
     /// The method to use to hoist the displayed entries.
     pub hoist_function: HoistType,
+    /// Whether to sort directories among files when sorting by size, rather than hoisting them automatically.
+    pub no_auto_hoist: bool,
+    /// Whether to separate hoisted entries from the rest of the listing with a blank line.
+    pub group_gap: bool,
+    /// The method to use to group the displayed entries.
+    pub group_by: GroupType,
+
+    /// The method to use to lay out the displayed entries.
+    pub layout: LayoutType,
+    /// The tabular format to output entries in, if any.
+    pub tabular: Option<TabularFormat>,
+    /// Whether to output entries in a stable format intended for scripts.
+    pub porcelain: bool,
 
     /// Whether to use color in the program's output.
     pub color: Option<bool>,
+    /// Whether to display only ASCII characters in place of decorative glyphs, such as tree connectors.
+    pub ascii: bool,
+    /// Whether to explain the resolved arguments instead of listing entries.
+    pub explain: bool,
+
     /// Whether to use human-readable sizes.
     pub human_readable: bool,
+    /// Whether human-readable sizes use powers of 1000 rather than powers of 1024.
+    pub si_units: bool,
+    /// The number of decimal places displayed in human-readable sizes, if not the default of one.
+    pub size_precision: Option<usize>,
+    /// Whether to display sizes as exact byte counts, even if human-readable formats are enabled.
+    pub raw_sizes: bool,
+    /// The number of bytes that displayed sizes are counted in, if not single bytes.
+    pub block_size: Option<u64>,
+    /// Whether to separate the digits of raw sizes into groups of thousands.
+    pub group_thousands: bool,
+    /// Whether to display numeric owner identifiers rather than resolving their names.
+    pub numeric_owner: bool,
+    /// The separator displayed after each detail column, if not a single space.
+    pub field_separator: Option<Box<str>>,
 }
 
 impl HasColor for Arguments {
@@ -77,22 +195,39 @@ impl HasColor for Arguments {
 #[derive(Clone, Debug)]
 pub enum Output {
     /// The arguments.
-    Arguments(Arguments),
+    Arguments(Box<Arguments>),
     /// Exit early.
     Exit,
     /// An error during parsing.
     Error(String),
 }
 
+/// The environment variable containing default arguments.
+pub const DEFAULT_ARGUMENTS_VARIABLE: &str = "RS_DEFAULT_ARGS";
+/// The environment variable that forces color on or off when it would otherwise be detected automatically.
+///
+/// This is only intended for testing, so that output is reproducible regardless of the real environment.
+pub const TEST_COLOR_VARIABLE: &str = "RS_TEST_COLOR";
+
 /// Parses the command-line arguments from standard in.
 ///
+/// Any arguments within the [`DEFAULT_ARGUMENTS_VARIABLE`] environment variable are parsed before the command-line
+/// arguments, allowing them to be overridden.
+///
 /// This function will exit early if the arguments could not be parsed.
 #[must_use]
 pub fn parse() -> Arguments {
-    let arguments = std::env::args().skip(1).collect::<Box<[_]>>();
+    let defaults = std::env::var(DEFAULT_ARGUMENTS_VARIABLE).unwrap_or_default();
+    let Some(mut arguments) = self::split_arguments(&defaults) else {
+        eprintln!("unterminated quote in {DEFAULT_ARGUMENTS_VARIABLE}");
+
+        std::process::exit(2);
+    };
+
+    arguments.extend(std::env::args().skip(1));
 
     match self::parse_arguments(Options::new(arguments.iter().map(String::as_str))) {
-        Output::Arguments(arguments) => arguments,
+        Output::Arguments(arguments) => *arguments,
         Output::Exit => {
             drop(arguments);
 
@@ -104,14 +239,204 @@ pub fn parse() -> Arguments {
             drop(arguments);
             drop(error);
 
-            std::process::exit(1);
+            std::process::exit(2);
+        }
+    }
+}
+
+/// Splits the given string into arguments, respecting simple shell-like quoting and escaping.
+///
+/// Returns [`None`] if the string contains an unterminated quote.
+fn split_arguments(string: &str) -> Option<Vec<String>> {
+    let mut arguments = Vec::new();
+    let mut current: Option<String> = None;
+    let mut characters = string.chars();
+
+    while let Some(character) = characters.next() {
+        match character {
+            c if c.is_whitespace() => arguments.extend(current.take()),
+            '\\' => current.get_or_insert_default().extend(characters.next()),
+            '\'' => {
+                let current = current.get_or_insert_default();
+
+                loop {
+                    match characters.next()? {
+                        '\'' => break,
+                        c => current.push(c),
+                    }
+                }
+            }
+            '"' => {
+                let current = current.get_or_insert_default();
+
+                loop {
+                    match characters.next()? {
+                        '"' => break,
+                        '\\' => match characters.next()? {
+                            c @ ('"' | '\\') => current.push(c),
+                            c => current.extend(['\\', c]),
+                        },
+                        c => current.push(c),
+                    }
+                }
+            }
+            c => current.get_or_insert_default().push(c),
         }
     }
+
+    arguments.extend(current);
+
+    Some(arguments)
+}
+
+/// Applies an option to the parser, given the option's value if it accepts one.
+///
+/// Returns an [`Output`] if parsing should stop early.
+type Handler = fn(&mut Parser, Option<&str>) -> Option<Output>;
+
+/// The state of the argument parser.
+#[expect(clippy::struct_excessive_bools, reason = "a lot of command-line arguments are flags")]
+#[derive(Debug, Default)]
+struct Parser {
+    /// The arguments parsed so far.
+    arguments: Arguments,
+    /// Whether to read additional paths from standard input.
+    read_stdin: bool,
+    /// The paths read from files given to `--paths-from`.
+    file_paths: Vec<Box<Path>>,
+    /// Whether paths read from standard input are separated by null bytes.
+    null_separated: bool,
+    /// Whether sorting by name distinguishes between uppercase and lowercase.
+    case_sensitive: bool,
+    /// Whether sorting by extension places entries without an extension last.
+    extensionless_last: bool,
+    /// Whether to display one entry per line, regardless of the selected layout.
+    one_per_line: bool,
+    /// The method to use to hoist the displayed entries, or [`None`] if it wasn't chosen explicitly.
+    hoist_function: Option<HoistType>,
+    /// The method to hoist the displayed entries with that's implied by the last `--sort`, if any.
+    sort_hoist_function: Option<HoistType>,
+    /// The displayed columns, in order, or [`None`] if they weren't chosen explicitly.
+    columns: Option<Box<[ColumnType]>>,
+    /// Whether a direction suffix given to `--sort` reverses the sorting order.
+    sort_suffix_reversed: bool,
+    /// Whether to show the help display once parsing has finished.
+    show_help: bool,
+}
+
+/// A command-line option, used both to parse arguments and to display the help listing.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct OptionDescriptor {
+    /// The option's short name.
+    pub short: Option<char>,
+    /// The option's long name.
+    pub long: &'static str,
+    /// Alternative long names that are accepted but not listed.
+    pub aliases: &'static [&'static str],
+    /// The option's description.
+    pub description: &'static str,
+    /// The values that the option accepts, the first of which is the default.
+    pub values: Option<&'static [&'static str]>,
+    /// The name of the arbitrary value that the option requires, if any.
+    pub argument: Option<&'static str>,
+    /// Whether the option accepts an arbitrary value, which must be attached to the option itself.
+    pub optional_value: bool,
+    /// Whether the option is omitted from the help listing.
+    pub hidden: bool,
+    /// The function that applies the option.
+    handler: Handler,
+    /// The function that reverts the option when it is given with a `no-` prefix, if it can be negated.
+    negation: Option<Handler>,
+}
+
+impl OptionDescriptor {
+    /// Creates a new [`OptionDescriptor`].
+    const fn new(long: &'static str, description: &'static str, handler: Handler) -> Self {
+        Self {
+            short: None,
+            long,
+            aliases: &[],
+            description,
+            values: None,
+            argument: None,
+            optional_value: false,
+            hidden: false,
+            handler,
+            negation: None,
+        }
+    }
+
+    /// Sets the option's short name.
+    const fn short(mut self, short: char) -> Self {
+        self.short = Some(short);
+        self
+    }
+
+    /// Sets the option's alternative long names.
+    const fn aliases(mut self, aliases: &'static [&'static str]) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Sets the values that the option accepts, the first of which is the default.
+    const fn values(mut self, values: &'static [&'static str]) -> Self {
+        self.values = Some(values);
+        self
+    }
+
+    /// Requires the option to be given an arbitrary value, described by the given name.
+    const fn argument(mut self, name: &'static str) -> Self {
+        self.argument = Some(name);
+        self
+    }
+
+    /// Allows the option to accept an arbitrary value, which must be attached to the option itself.
+    const fn optional_value(mut self) -> Self {
+        self.optional_value = true;
+        self
+    }
+
+    /// Omits the option from the help listing.
+    const fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    /// Allows the option to be negated by prefixing its long name with `no-`, reverting it with the given function.
+    const fn negation(mut self, negation: Handler) -> Self {
+        self.negation = Some(negation);
+        self
+    }
+
+    /// Returns whether the given option refers to this descriptor.
+    fn matches(&self, option: Opt<&str>) -> bool {
+        match option {
+            Opt::Short(short) => self.short == Some(short),
+            Opt::Long(long) => self.long == long || self.aliases.contains(&long),
+        }
+    }
+}
+
+/// Returns the descriptor that the given option refers to, and whether the option negates it.
+fn find_descriptor(option: Opt<&str>) -> Option<(&'static OptionDescriptor, bool)> {
+    // Options are matched exactly first, so that options which begin with `no-` themselves aren't mistaken for negations.
+    if let Some(descriptor) = OPTIONS.iter().flatten().find(|descriptor| descriptor.matches(option)) {
+        return Some((descriptor, false));
+    }
+
+    let Opt::Long(long) = option else { return None };
+    let option = Opt::Long(long.strip_prefix("no-")?);
+
+    OPTIONS
+        .iter()
+        .flatten()
+        .find(|descriptor| descriptor.negation.is_some() && descriptor.matches(option))
+        .map(|v| (v, true))
 }
 
 /// Parses the given options.
 fn parse_arguments<'arg>(mut options: Options<&'arg str, impl Iterator<Item = &'arg str>>) -> Output {
-    let mut arguments = Arguments::default();
+    let mut parser = Parser::default();
 
     while let Some(option) = options.next_opt().transpose() {
         let option = match option {
@@ -119,133 +444,1416 @@ fn parse_arguments<'arg>(mut options: Options<&'arg str, impl Iterator<Item = &'
             Err(error) => return Output::Error(format!("{error}")),
         };
 
-        match option {
-            Opt::Long("help") | Opt::Short('h') => {
-                self::print_help(&arguments, false).expect("failed to print help menu");
+        let Some((descriptor, negated)) = self::find_descriptor(option) else {
+            return Output::Error(self::unknown_option_message(option));
+        };
 
-                return Output::Exit;
+        if let Some(negation) = descriptor.negation.filter(|_| negated) {
+            if let Some(output) = negation(&mut parser, None) {
+                return output;
             }
-            Opt::Long("version") | Opt::Short('V') => {
-                println!("{}", env!("CARGO_PKG_VERSION"));
 
-                return Output::Exit;
-            }
-            Opt::Long("all") | Opt::Short('A') => {
-                arguments.show_hidden = true;
-            }
-            Opt::Long("show-permissions") | Opt::Short('P') => {
-                arguments.show_permissions = true;
-            }
-            Opt::Long("show-sizes") | Opt::Short('S') => {
-                arguments.show_sizes = true;
-            }
-            Opt::Long("show-modified") | Opt::Short('M') => {
-                arguments.show_modified = true;
-            }
-            Opt::Long("show-owner") | Opt::Short('O') => {
-                arguments.show_owner = true;
-            }
-            Opt::Long("resolve-symlinks") | Opt::Short('L') => {
-                arguments.show_symlinks = true;
-            }
-            Opt::Long("reverse") | Opt::Short('r') => {
-                arguments.sort_reversed = true;
-            }
-            Opt::Long("sort") | Opt::Short('s') => {
-                arguments.sort_function = match options.value() {
-                    Err(_) | Ok("name") => SortType::Name,
-                    Ok("size") => SortType::Size,
-                    Ok("created") => SortType::Created,
-                    Ok("modified") => SortType::Modified,
-                    Ok(other) => return Output::Error(format!("unknown sorting type: {other}")),
-                };
-            }
-            Opt::Long("hoist") | Opt::Short('H') => {
-                arguments.hoist_function = match options.value() {
-                    Err(_) | Ok("none") => HoistType::None,
-                    Ok("directories" | "dirs") => HoistType::Directories,
-                    Ok("hidden") => HoistType::Hidden,
-                    Ok("symlinks") => HoistType::Symlinks,
-                    Ok(other) => return Output::Error(format!("unknown hoisting type: {other}")),
-                };
-            }
-            Opt::Long("color") | Opt::Short('c') => {
-                arguments.color = match options.value() {
-                    Err(_) | Ok("auto") => None,
-                    Ok("always") => Some(true),
-                    Ok("never") => Some(false),
-                    Ok(other) => return Output::Error(format!("unknown color choice: {other}")),
-                }
-            }
-            Opt::Long("human-readable") | Opt::Short('U') => {
-                arguments.human_readable = true;
-            }
-            other => return Output::Error(format!("unknown argument: '{other}'")),
+            continue;
+        }
+
+        let value = if descriptor.values.is_some() || descriptor.argument.is_some() {
+            options.value().ok()
+        } else if descriptor.optional_value {
+            options.value_opt()
+        } else {
+            None
         };
+
+        if let Some(output) = (descriptor.handler)(&mut parser, value) {
+            return output;
+        }
+    }
+
+    if parser.show_help {
+        // Allow `--help OPTION` in addition to `--help=OPTION`.
+        if let Ok(Some(Arg::Positional(name))) = options.next_arg() {
+            return self::print_option_help(&parser.arguments, name);
+        }
+
+        self::print_help(&parser.arguments, false).expect("failed to print help menu");
+
+        return Output::Exit;
+    }
+
+    let Parser {
+        mut arguments,
+        mut read_stdin,
+        file_paths,
+        null_separated,
+        case_sensitive,
+        extensionless_last,
+        one_per_line,
+        hoist_function,
+        sort_hoist_function,
+        columns,
+        sort_suffix_reversed,
+        ..
+    } = parser;
+
+    // `--reverse` applies on top of any direction given to `--sort`, reversing it in turn.
+    arguments.sort_reversed ^= sort_suffix_reversed;
+
+    if arguments.sort_functions.is_empty() {
+        arguments.sort_functions = Box::new([SortType::default()]);
+    }
+
+    for sort_function in &mut arguments.sort_functions {
+        match *sort_function {
+            SortType::Name { case_sensitive: ref mut value } => *value = case_sensitive,
+            SortType::Extension { extensionless_last: ref mut value } => *value = extensionless_last,
+            SortType::Time(ref mut field) => *field = arguments.time_field,
+            _ => {}
+        }
+    }
+
+    // Directory sizes aren't comparable to file sizes, so directories are kept apart unless requested otherwise, either
+    // by `--no-auto-hoist` or by explicitly choosing any hoisting method, including `--hoist none`. Likewise, a sorting
+    // preset's hoisting method only applies if it's neither replaced by a later `--sort` nor overridden by `--hoist`.
+    arguments.hoist_function = hoist_function.or(sort_hoist_function).unwrap_or_else(|| {
+        let is_sized = arguments.sort_functions.first() == Some(&SortType::Size);
+
+        if is_sized && !arguments.no_auto_hoist {
+            HoistType::Directories
+        } else {
+            HoistType::None
+        }
+    });
+
+    // `--oneline` overrides `--format` wherever it's given, so it's applied once every layout option has been read.
+    if one_per_line {
+        arguments.layout = LayoutType::SingleColumn;
+    }
+
+    // Automatic detection depends on the real environment, which tests need to be independent of.
+    arguments.color = arguments.color.or_else(self::test_color);
+
+    // Tabular output is intended for other programs, so it should never contain color.
+    if arguments.tabular.is_some() {
+        arguments.color = Some(false);
+    }
+
+    // Porcelain output must not change for cosmetic reasons, so anything that only adjusts its appearance is disabled.
+    if arguments.porcelain {
+        arguments.color = Some(false);
+        arguments.layout = LayoutType::SingleColumn;
+        arguments.full_path = false;
+        arguments.relative_to = None;
+        arguments.truncate_names = None;
+        arguments.field_separator = None;
     }
 
+    self::resolve_columns(&mut arguments, columns);
+
     let mut paths = Vec::with_capacity(1);
 
+    // `getargs` stops yielding options after a lone `--`, so any remaining arguments are read as paths here even if
+    // they begin with a dash.
     while let Ok(Some(Arg::Positional(path))) = options.next_arg() {
-        paths.push(PathBuf::from(path).into_boxed_path());
+        if path == "-" {
+            read_stdin = true;
+        } else {
+            paths.push(PathBuf::from(path).into_boxed_path());
+        }
+    }
+
+    paths.extend(file_paths);
+
+    if read_stdin {
+        match self::read_stdin_paths(null_separated) {
+            Ok(stdin_paths) => paths.extend(stdin_paths),
+            Err(error) => return Output::Error(format!("failed to read paths from standard input: {error}")),
+        }
     }
 
     arguments.paths = paths.into_boxed_slice();
 
-    Output::Arguments(arguments)
+    Output::Arguments(Box::new(arguments))
 }
 
-/// Prints a help display.
+/// Returns whether color is forced on or off by the [`TEST_COLOR_VARIABLE`] environment variable, if it is.
+fn test_color() -> Option<bool> {
+    match std::env::var(TEST_COLOR_VARIABLE).as_deref() {
+        Ok("always") => Some(true),
+        Ok("never") => Some(false),
+        _ => None,
+    }
+}
+
+/// Resolves the displayed columns, either from the explicitly chosen columns or from the enabled details.
+fn resolve_columns(arguments: &mut Arguments, columns: Option<Box<[ColumnType]>>) {
+    // An explicit column list chooses which details are displayed, so the flags of any other details are overridden.
+    if let Some(ref columns) = columns {
+        arguments.show_permissions = columns.contains(&ColumnType::Permissions);
+        arguments.show_sizes = columns.contains(&ColumnType::Size);
+        arguments.show_child_count = columns.contains(&ColumnType::Children);
+        arguments.show_modified = columns.contains(&ColumnType::Modified);
+        arguments.show_owner = columns.contains(&ColumnType::Owner);
+        arguments.show_mime = columns.contains(&ColumnType::Mime);
+    }
+
+    // Porcelain output always displays its columns in a fixed order, even if they were chosen explicitly.
+    arguments.columns = match columns {
+        Some(columns) if !arguments.porcelain => {
+            let mut columns = columns.into_vec();
+
+            // The name is always displayed, as a listing without it isn't useful.
+            if !columns.contains(&ColumnType::Name) {
+                columns.push(ColumnType::Name);
+            }
+
+            columns.into_boxed_slice()
+        }
+        _ => [
+            (arguments.show_permissions, ColumnType::Permissions),
+            (arguments.show_sizes, ColumnType::Size),
+            (arguments.show_child_count, ColumnType::Children),
+            (arguments.show_modified, ColumnType::Modified),
+            (arguments.show_owner, ColumnType::Owner),
+            (arguments.show_mime, ColumnType::Mime),
+            (true, ColumnType::Name),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, column)| enabled.then_some(column))
+        .collect(),
+    };
+}
+
+/// Returns an error message for the given unknown option, suggesting the closest known option if one is similar enough.
+fn unknown_option_message(option: Opt<&str>) -> String {
+    let message = format!("unknown argument: '{option}'");
+
+    let Opt::Long(name) = option else { return message };
+    // Allow roughly one typo for every three characters, so that short names don't match everything.
+    let max_distance = (name.chars().count() / 3).max(1);
+
+    let suggestion = OPTIONS
+        .iter()
+        .flatten()
+        .filter(|descriptor| !descriptor.hidden)
+        .map(|descriptor| (self::edit_distance(name, descriptor.long), descriptor.long))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance);
+
+    match suggestion {
+        Some((_, long)) => format!("{message}, did you mean '--{long}'?"),
+        None => message,
+    }
+}
+
+/// Returns the Levenshtein distance between the two given strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut previous = (0..=b.len()).collect::<Vec<_>>();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a) in a.chars().enumerate() {
+        current[0] = i + 1;
+
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        core::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// Reads a list of paths from standard input, separated by either newlines or null bytes.
 ///
 /// # Errors
 ///
-/// This function will return an error if the display could not be printed.
-fn print_help(arguments: &Arguments, error: bool) -> Result<()> {
-    macro_rules! option {
-        ($short:literal, $long:literal, $desc:literal, [$default:literal, $($value:literal),* $(,)?]) => {
-            Some((Some($short), $long, $desc, Some(($default, &[$default, $($value),*]))))
-        };
-        ($short:literal, $long:literal, $desc:literal $(,)?) => {
-            Some((Some($short), $long, $desc, None))
-        };
-        ($long:literal, $desc:literal, [$default:literal, $($value:literal),* $(,)?]) => {
-            Some((None, $long, $desc, Some(($default, &[$default, $($value),*]))))
+/// This function will return an error if standard input could not be read.
+fn read_stdin_paths(null_separated: bool) -> Result<Vec<Box<Path>>> {
+    let mut buffer = Vec::new();
+
+    std::io::stdin().lock().read_to_end(&mut buffer)?;
+
+    let separator = if null_separated { b'\0' } else { b'\n' };
+
+    Ok(buffer
+        .split(|byte| *byte == separator)
+        .map(|path| String::from_utf8_lossy(path).trim_end_matches('\r').to_owned())
+        .filter(|path| !path.is_empty())
+        .map(|path| PathBuf::from(path).into_boxed_path())
+        .collect())
+}
+
+/// Reads a list of paths from the given file, one per line, skipping blank lines and comments starting with `#`.
+///
+/// # Errors
+///
+/// This function will return an error if the file could not be read.
+fn read_file_paths(file: &str) -> Result<Vec<Box<Path>>> {
+    let contents = std::fs::read_to_string(file)?;
+
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|path| PathBuf::from(path).into_boxed_path())
+        .collect())
+}
+
+/// Parses the value of the `--sort` option.
+fn parse_sort(parser: &mut Parser, value: Option<&str>) -> Option<Output> {
+    parser.sort_suffix_reversed = false;
+    parser.sort_hoist_function = None;
+
+    let Some(value) = value else {
+        parser.arguments.sort_functions = Box::new([SortType::default()]);
+
+        return None;
+    };
+
+    let mut sort_functions = Vec::with_capacity(1);
+
+    for value in value.split(',') {
+        let (value, direction) = match value.trim().split_once(':') {
+            Some((value, direction)) => (value, Some(direction)),
+            None => (value.trim(), None),
         };
-        ($long:literal, $desc:literal $(,)?) => {
-            Some((None, $long, $desc, None))
+        let sort_function = match value {
+            "name" => SortType::default(),
+            "size" => SortType::Size,
+            "created" => SortType::Created,
+            "modified" => SortType::Modified,
+            "type" => SortType::Type,
+            "length" | "width" => SortType::Length,
+            "accessed" => SortType::Accessed,
+            "time" => SortType::Time(TimeField::default()),
+            "none" => SortType::None,
+            "owner" => SortType::Owner,
+            "extension" | "ext" => SortType::Extension { extensionless_last: false },
+            "permissions" | "mode" => SortType::Permissions,
+            // A preset for browsing source trees, which groups files by extension beneath their directories.
+            "source" => {
+                parser.sort_hoist_function = Some(HoistType::Directories);
+                sort_functions.push(SortType::Extension { extensionless_last: false });
+
+                SortType::default()
+            }
+            other => return Some(Output::Error(format!("unknown sorting type: {other}"))),
         };
+
+        // Directions are relative to each order's natural direction, which is descending for sizes and timestamps.
+        match direction {
+            None => {}
+            Some("asc") => parser.sort_suffix_reversed = sort_function.is_descending(),
+            Some("desc") => parser.sort_suffix_reversed = !sort_function.is_descending(),
+            Some(other) => return Some(Output::Error(format!("unknown sorting direction: {other}"))),
+        }
+
+        sort_functions.push(sort_function);
+    }
+
+    parser.arguments.sort_functions = sort_functions.into_boxed_slice();
+
+    None
+}
+
+/// Parses the value of the `--columns` option.
+fn parse_columns(parser: &mut Parser, value: Option<&str>) -> Option<Output> {
+    let Some(value) = value else {
+        return Some(Output::Error("missing columns for option 'columns'".into()));
+    };
+
+    let mut columns = Vec::with_capacity(value.split(',').count());
+
+    for value in value.split(',') {
+        columns.push(match value.trim() {
+            "permissions" | "perms" | "mode" => ColumnType::Permissions,
+            "size" => ColumnType::Size,
+            "children" => ColumnType::Children,
+            "modified" | "time" => ColumnType::Modified,
+            "owner" => ColumnType::Owner,
+            "mime" | "type" => ColumnType::Mime,
+            "name" => ColumnType::Name,
+            other => return Some(Output::Error(format!("unknown column: {other}"))),
+        });
     }
 
-    const OPTIONS: &[Option<HelpOption<'static>>] = &[
-        option!('h', "help", "Show this program's usage."),
-        option!('V', "version", "Show this program's version."),
-        None,
-        option!('A', "all", "Display hidden files (excluding . and ..)."),
-        option!('P', "show-permissions", "Display entry permissions."),
-        option!('S', "show-sizes", "Display file sizes."),
-        option!('M', "show-modified", "Display entry modification date."),
-        option!('O', "show-owner", "Display entry owner."),
-        option!('L', "resolve-symlinks", "Display resolved symbolic links."),
-        None,
-        option!('r', "reverse", "Reverse the displayed sorting order."),
-        option!(
-            's',
+    parser.columns = Some(columns.into_boxed_slice());
+
+    None
+}
+
+/// Parses the value of the `--type` option.
+fn parse_type(parser: &mut Parser, value: Option<&str>) -> Option<Output> {
+    let Some(value) = value else {
+        return Some(Output::Error("missing kinds for option 'type'".into()));
+    };
+
+    let mut entry_kinds = Vec::with_capacity(value.len());
+
+    for character in value.chars() {
+        match character {
+            'f' => entry_kinds.extend([EntryKind::File, EntryKind::Executable]),
+            'd' => entry_kinds.push(EntryKind::Directory),
+            'l' => entry_kinds.push(EntryKind::Symlink),
+            'x' => entry_kinds.push(EntryKind::Executable),
+            other => return Some(Output::Error(format!("unknown entry kind: {other}"))),
+        }
+    }
+
+    parser.arguments.entry_kinds = Some(entry_kinds.into_boxed_slice());
+
+    None
+}
+
+/// Parses the given size in bytes, returning [`None`] if it is invalid.
+///
+/// Sizes are an optional count followed by an optional unit, such as `512`, `1K`, `4KiB`, or `1MB`. Units ending in `B`
+/// without an `i` are powers of 1000, while every other unit is a power of 1024.
+fn parse_size(value: &str) -> Option<u64> {
+    /// The unit prefixes, in increasing order of magnitude.
+    const PREFIXES: [char; 6] = ['K', 'M', 'G', 'T', 'P', 'E'];
+
+    if value.is_empty() {
+        return None;
+    }
+
+    let digits = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(value.len());
+    let (count, unit) = value.split_at(digits);
+    let count = if count.is_empty() { Ok(1) } else { count.parse::<u64>() };
+
+    let mut characters = unit.chars();
+    let exponent = characters.next().map_or(Some(0), |prefix| {
+        (1..).zip(PREFIXES).find(|(_, v)| v.eq_ignore_ascii_case(&prefix)).map(|(exponent, _)| exponent)
+    });
+    let base: u64 = match characters.as_str() {
+        "" | "iB" => 1024,
+        "B" => 1000,
+        _ => return None,
+    };
+
+    exponent
+        .and_then(|exponent| base.checked_pow(exponent))
+        .zip(count.ok())
+        .and_then(|(unit, count)| unit.checked_mul(count))
+}
+
+/// Parses the value of the `--block-size` option.
+fn parse_block_size(parser: &mut Parser, value: Option<&str>) -> Option<Output> {
+    let Some(value) = value.filter(|v| !v.is_empty()) else {
+        return Some(Output::Error("missing size for option 'block-size'".into()));
+    };
+
+    let Some(size) = self::parse_size(value).filter(|size| *size > 0) else {
+        return Some(Output::Error(format!("invalid block size: {value}")));
+    };
+
+    parser.arguments.block_size = Some(size);
+
+    None
+}
+
+/// Parses the value of the `--min-size` or `--max-size` options, returning the size in bytes that it describes.
+fn parse_size_limit(option: &str, value: Option<&str>) -> core::result::Result<u64, Output> {
+    let Some(value) = value.filter(|v| !v.is_empty()) else {
+        return Err(Output::Error(format!("missing size for option '{option}'")));
+    };
+
+    self::parse_size(value).ok_or_else(|| Output::Error(format!("invalid size: {value}")))
+}
+
+/// Parses the value of the `--newer-than` or `--older-than` options, returning the time that it describes.
+///
+/// Times are either a date, such as `2024-06-01`, optionally followed by a time, such as `2024-06-01T12:30`, or a
+/// duration before now, such as `3d` or `2h`. Dates and times are read in the local time zone.
+fn parse_cutoff(option: &str, value: Option<&str>) -> core::result::Result<SystemTime, Output> {
+    /// The units that durations may be given in, alongside their lengths in seconds.
+    const UNITS: [(char, u64); 5] = [('s', 1), ('m', 60), ('h', 60 * 60), ('d', 24 * 60 * 60), ('w', 7 * 24 * 60 * 60)];
+
+    let Some(value) = value.filter(|v| !v.is_empty()) else {
+        return Err(Output::Error(format!("missing time for option '{option}'")));
+    };
+
+    let duration = value.split_at_checked(value.len() - 1).and_then(|(count, unit)| {
+        let (_, seconds) = UNITS.iter().find(|(name, _)| unit.starts_with(*name))?;
+
+        count.parse::<u64>().ok()?.checked_mul(*seconds).map(Duration::from_secs)
+    });
+
+    if let Some(duration) = duration {
+        return SystemTime::now()
+            .checked_sub(duration)
+            .ok_or_else(|| Output::Error(format!("invalid time: {value}")));
+    }
+
+    let date_time = Date::parse(value, format_description!("[year]-[month]-[day]"))
+        .map(|date| date.with_time(Time::MIDNIGHT))
+        .or_else(|_| PrimitiveDateTime::parse(value, format_description!("[year]-[month]-[day]T[hour]:[minute]")))
+        .or_else(|_| {
+            PrimitiveDateTime::parse(value, format_description!("[year]-[month]-[day]T[hour]:[minute]:[second]"))
+        });
+
+    let Ok(date_time) = date_time else {
+        return Err(Output::Error(format!("invalid time: {value}")));
+    };
+
+    let offset = UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC);
+
+    Ok(SystemTime::from(date_time.assume_offset(offset)))
+}
+
+/// Parses the value of the `--format` option.
+fn parse_format(parser: &mut Parser, value: Option<&str>) -> Option<Output> {
+    let arguments = &mut parser.arguments;
+
+    match value {
+        None | Some("single-column") => arguments.layout = LayoutType::SingleColumn,
+        Some("long") => {
+            arguments.layout = LayoutType::SingleColumn;
+            arguments.show_permissions = true;
+            arguments.show_sizes = true;
+            arguments.show_modified = true;
+            arguments.show_owner = true;
+        }
+        Some("grid" | "vertical") => arguments.layout = LayoutType::Grid,
+        Some("across" | "horizontal") => arguments.layout = LayoutType::Across,
+        Some("commas" | "comma") => arguments.layout = LayoutType::Commas,
+        Some(other) => return Some(Output::Error(format!("unknown format type: {other}"))),
+    }
+
+    None
+}
+
+/// Every supported option, in the order that they are listed in the help display, with [`None`] representing a
+/// separator.
+pub(crate) const OPTIONS: &[Option<OptionDescriptor>] = &[
+    Some(
+        OptionDescriptor::new(
+            "help",
+            "Show this program's usage, or the usage of the given option.",
+            |parser, value| {
+                if let Some(name) = value {
+                    return Some(self::print_option_help(&parser.arguments, name));
+                }
+
+                parser.show_help = true;
+
+                None
+            },
+        )
+        .short('h')
+        .optional_value(),
+    ),
+    Some(
+        OptionDescriptor::new("version", "Show this program's version.", |_, _| {
+            println!("{}", env!("CARGO_PKG_VERSION"));
+
+            Some(Output::Exit)
+        })
+        .short('V'),
+    ),
+    Some(
+        OptionDescriptor::new("generate-completions", "Print a shell completion script.", |_, value| {
+            let shell = match value {
+                Some("bash") => Shell::Bash,
+                Some("zsh") => Shell::Zsh,
+                Some("fish") => Shell::Fish,
+                Some(other) => return Some(Output::Error(format!("unknown shell: {other}"))),
+                None => return Some(Output::Error("missing shell for option 'generate-completions'".into())),
+            };
+
+            Shell::write_completions(shell, &mut std::io::stdout(), OPTIONS)
+                .expect("failed to print completion script");
+
+            Some(Output::Exit)
+        })
+        .values(&["bash", "zsh", "fish"])
+        .hidden(),
+    ),
+    Some(
+        OptionDescriptor::new("explain", "Show the enabled columns and resolved ordering, then exit.", |parser, _| {
+            parser.arguments.explain = true;
+
+            None
+        })
+        .aliases(&["list-columns"]),
+    ),
+    None,
+    Some(
+        OptionDescriptor::new("all", "Display hidden files (excluding . and ..).", |parser, _| {
+            parser.arguments.show_hidden = true;
+
+            None
+        })
+        .negation(|parser, _| {
+            parser.arguments.show_hidden = false;
+
+            None
+        })
+        .short('A'),
+    ),
+    Some(OptionDescriptor::new("only-hidden", "Display only hidden files.", |parser, _| {
+        parser.arguments.show_hidden = true;
+        parser.arguments.only_hidden = true;
+
+        None
+    })),
+    Some(
+        OptionDescriptor::new("ignore-backups", "Hide backup files (ending with ~).", |parser, _| {
+            parser.arguments.hide_backups = true;
+
+            None
+        })
+        .negation(|parser, _| {
+            parser.arguments.hide_backups = false;
+
+            None
+        })
+        .short('B'),
+    ),
+    Some(
+        OptionDescriptor::new(
+            "type",
+            "Display only the given kinds of entries: f (files), d (directories), l (symlinks), or x (executables).",
+            self::parse_type,
+        )
+        .argument("KINDS"),
+    ),
+    Some(OptionDescriptor::new("dirs-only", "Display only directories, like '--type d'.", |parser, _| {
+        parser.arguments.entry_kinds = Some(Box::new([EntryKind::Directory]));
+
+        None
+    })),
+    Some(OptionDescriptor::new("files-only", "Display only files, like '--type f'.", |parser, _| {
+        parser.arguments.entry_kinds = Some(Box::new([EntryKind::File, EntryKind::Executable]));
+
+        None
+    })),
+    Some(OptionDescriptor::new(
+        "dedup-hardlinks",
+        "Hide additional hard links to files that have already been listed.",
+        |parser, _| {
+            parser.arguments.dedup_hardlinks = true;
+
+            None
+        },
+    )),
+    Some(
+        OptionDescriptor::new("newer-than", "Display only entries modified after the given time.", |parser, value| {
+            match self::parse_cutoff("newer-than", value) {
+                Ok(cutoff) => parser.arguments.newer_than = Some(cutoff),
+                Err(output) => return Some(output),
+            }
+
+            None
+        })
+        .argument("TIME"),
+    ),
+    Some(
+        OptionDescriptor::new("older-than", "Display only entries modified before the given time.", |parser, value| {
+            match self::parse_cutoff("older-than", value) {
+                Ok(cutoff) => parser.arguments.older_than = Some(cutoff),
+                Err(output) => return Some(output),
+            }
+
+            None
+        })
+        .argument("TIME"),
+    ),
+    Some(
+        OptionDescriptor::new("min-size", "Display only entries at least as large as the given size.", |parser, value| {
+            match self::parse_size_limit("min-size", value) {
+                Ok(size) => parser.arguments.min_size = Some(size),
+                Err(output) => return Some(output),
+            }
+
+            None
+        })
+        .argument("SIZE"),
+    ),
+    Some(
+        OptionDescriptor::new("max-size", "Display only entries at most as large as the given size.", |parser, value| {
+            match self::parse_size_limit("max-size", value) {
+                Ok(size) => parser.arguments.max_size = Some(size),
+                Err(output) => return Some(output),
+            }
+
+            None
+        })
+        .argument("SIZE"),
+    ),
+    Some(OptionDescriptor::new(
+        "size-filter-dirs",
+        "Hide directories when filtering by size, rather than always displaying them.",
+        |parser, _| {
+            parser.arguments.size_filter_dirs = true;
+
+            None
+        },
+    )),
+    Some(
+        OptionDescriptor::new("show-permissions", "Display entry permissions.", |parser, _| {
+            parser.arguments.show_permissions = true;
+
+            None
+        })
+        .negation(|parser, _| {
+            parser.arguments.show_permissions = false;
+
+            None
+        })
+        .short('P'),
+    ),
+    Some(
+        OptionDescriptor::new("show-sizes", "Display file sizes.", |parser, _| {
+            parser.arguments.show_sizes = true;
+
+            None
+        })
+        .negation(|parser, _| {
+            parser.arguments.show_sizes = false;
+
+            None
+        })
+        .short('S'),
+    ),
+    Some(OptionDescriptor::new(
+        "symlink-target-size",
+        "Display the sizes of the targets of symbolic links, rather than of the links themselves.",
+        |parser, _| {
+            parser.arguments.symlink_target_size = true;
+
+            None
+        },
+    )),
+    Some(OptionDescriptor::new(
+        "show-child-count",
+        "Display the number of entries within each directory.",
+        |parser, _| {
+            parser.arguments.show_child_count = true;
+
+            None
+        },
+    )),
+    Some(OptionDescriptor::new("mark-sparse", "Highlight the sizes of sparse files.", |parser, _| {
+        parser.arguments.mark_sparse = true;
+
+        None
+    })),
+    Some(
+        OptionDescriptor::new("show-modified", "Display entry modification date.", |parser, _| {
+            parser.arguments.show_modified = true;
+
+            None
+        })
+        .negation(|parser, _| {
+            parser.arguments.show_modified = false;
+
+            None
+        })
+        .short('M'),
+    ),
+    Some(
+        OptionDescriptor::new("time-scale", "Color timestamps by their age.", |parser, _| {
+            parser.arguments.time_scale = true;
+
+            None
+        })
+        .aliases(&["color-scale"]),
+    ),
+    Some(
+        OptionDescriptor::new("show-owner", "Display entry owner.", |parser, _| {
+            parser.arguments.show_owner = true;
+
+            None
+        })
+        .negation(|parser, _| {
+            parser.arguments.show_owner = false;
+
+            None
+        })
+        .short('O'),
+    ),
+    Some(
+        OptionDescriptor::new(
+            "mime",
+            "Display file content types, as guessed from their extensions.",
+            |parser, _| {
+                parser.arguments.show_mime = true;
+
+                None
+            },
+        )
+        .negation(|parser, _| {
+            parser.arguments.show_mime = false;
+
+            None
+        }),
+    ),
+    Some(
+        OptionDescriptor::new("resolve-symlinks", "Display resolved symbolic links.", |parser, _| {
+            parser.arguments.show_symlinks = true;
+
+            None
+        })
+        .negation(|parser, _| {
+            parser.arguments.show_symlinks = false;
+
+            None
+        })
+        .short('L'),
+    ),
+    Some(
+        OptionDescriptor::new("compact-symlinks", "Display symbolic links without their targets.", |parser, _| {
+            parser.arguments.compact_symlinks = true;
+
+            None
+        })
+        .aliases(&["no-symlink-arrow"]),
+    ),
+    Some(OptionDescriptor::new(
+        "no-highlight-broken",
+        "Display broken symbolic links like any other symbolic link.",
+        |parser, _| {
+            parser.arguments.plain_broken_symlinks = true;
+
+            None
+        },
+    )),
+    Some(
+        OptionDescriptor::new(
+            "full-path",
+            "Display each entry's full absolute path instead of its name.",
+            |parser, _| {
+                parser.arguments.full_path = true;
+
+                None
+            },
+        )
+        .negation(|parser, _| {
+            parser.arguments.full_path = false;
+
+            None
+        })
+        .aliases(&["show-full-path"]),
+    ),
+    Some(
+        OptionDescriptor::new(
+            "relative-to",
+            "Display entry paths relative to the given directory, where possible.",
+            |parser, value| {
+                let Some(value) = value else {
+                    return Some(Output::Error("missing directory for option 'relative-to'".into()));
+                };
+
+                match std::path::absolute(value) {
+                    Ok(path) => parser.arguments.relative_to = Some(path.into_boxed_path()),
+                    Err(error) => return Some(Output::Error(format!("invalid directory '{value}': {error}"))),
+                }
+
+                None
+            },
+        )
+        .argument("DIR"),
+    ),
+    Some(
+        OptionDescriptor::new(
+            "truncate-names",
+            "Shorten names longer than the given width with an ellipsis.",
+            |parser, value| {
+                match value.map(str::parse) {
+                    Some(Ok(width)) => parser.arguments.truncate_names = Some(width),
+                    Some(Err(error)) => return Some(Output::Error(format!("invalid name width: {error}"))),
+                    None => return Some(Output::Error("missing width for option 'truncate-names'".into())),
+                }
+
+                None
+            },
+        )
+        .argument("N"),
+    ),
+    Some(
+        OptionDescriptor::new("max-entries", "Display at most the given number of entries per listing.", |parser, value| {
+            match value.map(str::parse) {
+                Some(Ok(count)) => parser.arguments.max_entries = Some(count),
+                Some(Err(error)) => return Some(Output::Error(format!("invalid entry count: {error}"))),
+                None => return Some(Output::Error("missing count for option 'max-entries'".into())),
+            }
+
+            None
+        })
+        .argument("N"),
+    ),
+    Some(
+        OptionDescriptor::new("quote-names", "Wrap each displayed name in double quotes.", |parser, _| {
+            parser.arguments.quote_names = true;
+
+            None
+        })
+        .negation(|parser, _| {
+            parser.arguments.quote_names = false;
+
+            None
+        })
+        .short('Q'),
+    ),
+    Some(
+        OptionDescriptor::new("escape", "Display non-printable characters in names as C-style escapes.", |parser, _| {
+            parser.arguments.escape_names = true;
+
+            None
+        })
+        .short('b'),
+    ),
+    Some(
+        OptionDescriptor::new(
+            "indicator-style",
+            "Select which indicators are displayed after entry names.",
+            |parser, value| {
+                parser.arguments.indicator_style = match value {
+                    None | Some("executable") => IndicatorStyle::Executable,
+                    Some("slash") => IndicatorStyle::Slash,
+                    Some("none") => IndicatorStyle::None,
+                    Some("classify") => IndicatorStyle::Classify,
+                    Some(other) => return Some(Output::Error(format!("unknown indicator style: {other}"))),
+                };
+
+                None
+            },
+        )
+        .values(&["executable", "slash", "none", "classify"]),
+    ),
+    Some(
+        OptionDescriptor::new("classify", "Display indicators classifying every kind of entry.", |parser, _| {
+            parser.arguments.indicator_style = IndicatorStyle::Classify;
+
+            None
+        })
+        .short('F'),
+    ),
+    Some(OptionDescriptor::new("no-dir-slash", "Omit the trailing separator from directory names.", |parser, _| {
+        parser.arguments.no_dir_slash = true;
+
+        None
+    })),
+    Some(
+        OptionDescriptor::new("no-exec-suffix", "Omit the trailing asterisk from executable names.", |parser, _| {
+            parser.arguments.no_exec_suffix = true;
+
+            None
+        })
+        .aliases(&["classify-colors-only"]),
+    ),
+    Some(
+        OptionDescriptor::new("summary", "Display a summary of the listed entries.", |parser, _| {
+            parser.arguments.show_summary = true;
+
+            None
+        })
+        .negation(|parser, _| {
+            parser.arguments.show_summary = false;
+
+            None
+        }),
+    ),
+    Some(OptionDescriptor::new(
+        "summary-ages",
+        "Display how recently the listed entries were modified within the summary.",
+        |parser, _| {
+            parser.arguments.show_summary = true;
+            parser.arguments.summary_ages = true;
+
+            None
+        },
+    )),
+    Some(OptionDescriptor::new(
+        "git-status-summary",
+        "Display the status of the git repository containing each listed directory.",
+        |parser, _| {
+            parser.arguments.git_status_summary = true;
+
+            None
+        },
+    )),
+    Some(OptionDescriptor::new(
+        "grand-total",
+        "Display the total size of the files listed across every path.",
+        |parser, _| {
+            parser.arguments.grand_total = true;
+
+            None
+        },
+    )),
+    Some(OptionDescriptor::new(
+        "header-details",
+        "Display each listed directory's own details within its header.",
+        |parser, _| {
+            parser.arguments.header_details = true;
+
+            None
+        },
+    )),
+    Some(OptionDescriptor::new(
+        "always-header",
+        "Display a header even when listing a single directory.",
+        |parser, _| {
+            parser.arguments.always_header = true;
+
+            None
+        },
+    )),
+    Some(OptionDescriptor::new(
+        "header-counts",
+        "Display the number of entries listed beneath each directory's header.",
+        |parser, _| {
+            parser.arguments.header_counts = true;
+
+            None
+        },
+    )),
+    None,
+    Some(
+        OptionDescriptor::new("recursive", "List subdirectories recursively.", |parser, _| {
+            parser.arguments.recursive = true;
+
+            None
+        })
+        .negation(|parser, _| {
+            parser.arguments.recursive = false;
+
+            None
+        })
+        .short('R'),
+    ),
+    Some(OptionDescriptor::new(
+        "depth-first",
+        "List each directory's subdirectories before its siblings when listing recursively.",
+        |parser, _| {
+            parser.arguments.breadth_first = false;
+
+            None
+        },
+    )),
+    Some(OptionDescriptor::new("breadth-first", "List directories level by level when listing recursively.", |parser, _| {
+        parser.arguments.breadth_first = true;
+
+        None
+    })),
+    Some(OptionDescriptor::new("tree", "Display subdirectories recursively as a tree.", |parser, _| {
+        parser.arguments.tree = true;
+
+        None
+    })),
+    Some(OptionDescriptor::new(
+        "follow-symlinks",
+        "Descend into symbolic links to directories when listing recursively.",
+        |parser, _| {
+            parser.arguments.follow_symlinks = true;
+
+            None
+        },
+    )),
+    Some(OptionDescriptor::new(
+        "dereference-args",
+        "Display listed symbolic links to directories by their resolved paths.",
+        |parser, _| {
+            parser.arguments.dereference_args = true;
+
+            None
+        },
+    )),
+    Some(OptionDescriptor::new(
+        "merge",
+        "Display the entries of every given path as a single sorted listing.",
+        |parser, _| {
+            parser.arguments.merge = true;
+
+            None
+        },
+    )),
+    #[cfg(feature = "watch")]
+    Some(OptionDescriptor::new(
+        "watch",
+        "Re-display the listing whenever the listed directories change.",
+        |parser, _| {
+            parser.arguments.watch = true;
+
+            None
+        },
+    )),
+    None,
+    Some(
+        OptionDescriptor::new("reverse", "Reverse the displayed sorting order.", |parser, _| {
+            parser.arguments.sort_reversed = true;
+
+            None
+        })
+        .negation(|parser, _| {
+            parser.arguments.sort_reversed = false;
+
+            None
+        })
+        .short('r'),
+    ),
+    Some(
+        OptionDescriptor::new(
             "sort",
-            "Sort displayed entries in the specified order.",
-            ["name", "size", "created", "modified"]
-        ),
-        None,
-        option!(
-            'H',
-            "hoist",
-            "Group specific entries at the top of the listing.",
-            ["none", "directories", "dirs", "hidden", "symlinks"]
-        ),
-        None,
-        option!('c', "color", "Set whether to use color in the program's output.", ["auto", "always", "never"]),
-        option!('U', "human-readable", "Use more human-readable formats."),
-    ];
+            "Sort displayed entries in the specified order, using any later orders to break ties. Append ':asc' or ':desc' \
+             to set the direction.",
+            self::parse_sort,
+        )
+        .short('s')
+        .values(&[
+            "name",
+            "size",
+            "created",
+            "modified",
+            "type",
+            "length",
+            "width",
+            "accessed",
+            "time",
+            "none",
+            "owner",
+            "extension",
+            "ext",
+            "source",
+            "permissions",
+            "mode",
+        ]),
+    ),
+    Some(
+        OptionDescriptor::new(
+            "time",
+            "Select the timestamp to display and to sort by with '--sort time'.",
+            |parser, value| {
+                parser.arguments.time_field = match value {
+                    None | Some("modified") => TimeField::Modified,
+                    Some("created") => TimeField::Created,
+                    Some("accessed") => TimeField::Accessed,
+                    Some(other) => return Some(Output::Error(format!("unknown time type: {other}"))),
+                };
+
+                None
+            },
+        )
+        .values(&["modified", "created", "accessed"]),
+    ),
+    Some(
+        OptionDescriptor::new("time-style", "Select the style that displayed timestamps are formatted in.", |parser, value| {
+            parser.arguments.time_style = match value {
+                None | Some("default") => TimeStyle::Default,
+                Some("iso") => TimeStyle::Iso,
+                Some("long-iso") => TimeStyle::LongIso,
+                Some("full-iso") => TimeStyle::FullIso,
+                Some("relative") => TimeStyle::Relative,
+                Some(other) => match other.strip_prefix('+') {
+                    Some(format) => match time::format_description::parse_owned::<2>(format) {
+                        Ok(format) => TimeStyle::Custom(format),
+                        Err(error) => return Some(Output::Error(format!("invalid time format: {error}"))),
+                    },
+                    None => return Some(Output::Error(format!("unknown time style: {other}"))),
+                },
+            };
+
+            None
+        })
+        .values(&["default", "iso", "long-iso", "full-iso", "relative", "+FORMAT"]),
+    ),
+    Some(OptionDescriptor::new(
+        "case-sensitive",
+        "Distinguish between uppercase and lowercase when sorting by name.",
+        |parser, _| {
+            parser.case_sensitive = true;
+
+            None
+        },
+    )),
+    Some(OptionDescriptor::new(
+        "ignore-case",
+        "Ignore case when sorting by name (the default).",
+        |parser, _| {
+            parser.case_sensitive = false;
+
+            None
+        },
+    )),
+    Some(OptionDescriptor::new(
+        "extensionless-last",
+        "Place entries without an extension last when sorting by extension.",
+        |parser, _| {
+            parser.extensionless_last = true;
+
+            None
+        },
+    )),
+    None,
+    Some(
+        OptionDescriptor::new("hoist", "Group specific entries at the top of the listing.", |parser, value| {
+            parser.hoist_function = Some(match value {
+                None | Some("none") => HoistType::None,
+                Some("directories" | "dirs") => HoistType::Directories,
+                Some("hidden") => HoistType::Hidden,
+                Some("symlinks") => HoistType::Symlinks,
+                Some("executables" | "exec") => HoistType::Executables,
+                Some("files") => HoistType::Files,
+                Some(other) => match other.strip_prefix("ext:").map(|v| v.trim_start_matches('.')) {
+                    Some(extension) if !extension.is_empty() => {
+                        HoistType::Extension(HoistExtension { extension: extension.into() })
+                    }
+                    _ => return Some(Output::Error(format!("unknown hoisting type: {other}"))),
+                },
+            });
+
+            None
+        })
+        .short('H')
+        .values(&[
+            "none",
+            "directories",
+            "dirs",
+            "hidden",
+            "symlinks",
+            "executables",
+            "exec",
+            "files",
+            "ext:EXTENSION",
+        ]),
+    ),
+    Some(OptionDescriptor::new(
+        "no-auto-hoist",
+        "Sort directories among files when sorting by size.",
+        |parser, _| {
+            parser.arguments.no_auto_hoist = true;
 
+            None
+        },
+    )),
+    Some(
+        OptionDescriptor::new("group-gap", "Separate hoisted entries with a blank line.", |parser, _| {
+            parser.arguments.group_gap = true;
+
+            None
+        })
+        .aliases(&["group-separator"]),
+    ),
+    Some(
+        OptionDescriptor::new("group-by", "Group displayed entries into labeled sections.", |parser, value| {
+            parser.arguments.group_by = match value {
+                None | Some("none") => GroupType::None,
+                Some("type") => GroupType::Type,
+                Some(other) => return Some(Output::Error(format!("unknown grouping type: {other}"))),
+            };
+
+            None
+        })
+        .values(&["none", "type"]),
+    ),
+    None,
+    Some(
+        OptionDescriptor::new("format", "Lay out displayed entries in the specified format.", self::parse_format)
+            .values(&["single-column", "long", "grid", "vertical", "across", "horizontal", "commas", "comma"]),
+    ),
+    Some(
+        OptionDescriptor::new("across", "Lay out displayed entries in rows rather than columns.", |parser, _| {
+            parser.arguments.layout = LayoutType::Across;
+
+            None
+        })
+        .short('x'),
+    ),
+    Some(
+        OptionDescriptor::new("oneline", "Display one entry per line, regardless of the format.", |parser, _| {
+            parser.one_per_line = true;
+
+            None
+        })
+        .short('1')
+        .aliases(&["one"]),
+    ),
+    Some(OptionDescriptor::new("csv", "Output entries as comma-separated values.", |parser, _| {
+        parser.arguments.tabular = Some(TabularFormat::Csv);
+
+        None
+    })),
+    Some(OptionDescriptor::new("tsv", "Output entries as tab-separated values.", |parser, _| {
+        parser.arguments.tabular = Some(TabularFormat::Tsv);
+
+        None
+    })),
+    Some(OptionDescriptor::new(
+        "porcelain",
+        "Output entries in a stable format intended for scripts.",
+        |parser, _| {
+            parser.arguments.porcelain = true;
+
+            None
+        },
+    )),
+    None,
+    Some(
+        OptionDescriptor::new("color", "Set whether to use color in the program's output.", |parser, value| {
+            parser.arguments.color = match value {
+                None | Some("auto") => None,
+                Some("always") => Some(true),
+                Some("never") => Some(false),
+                Some(other) => return Some(Output::Error(format!("unknown color choice: {other}"))),
+            };
+
+            None
+        })
+        .negation(|parser, _| {
+            parser.arguments.color = Some(false);
+
+            None
+        })
+        .short('c')
+        .values(&["auto", "always", "never"]),
+    ),
+    Some(OptionDescriptor::new(
+        "ascii",
+        "Display only ASCII characters in place of decorative glyphs, such as tree connectors.",
+        |parser, _| {
+            parser.arguments.ascii = true;
+
+            None
+        },
+    )),
+    Some(
+        OptionDescriptor::new("human-readable", "Use more human-readable formats.", |parser, _| {
+            parser.arguments.human_readable = true;
+
+            None
+        })
+        .negation(|parser, _| {
+            parser.arguments.human_readable = false;
+
+            None
+        })
+        .short('U'),
+    ),
+    Some(OptionDescriptor::new("si", "Use powers of 1000 rather than 1024 for human-readable sizes.", |parser, _| {
+        parser.arguments.human_readable = true;
+        parser.arguments.si_units = true;
+
+        None
+    })),
+    Some(
+        OptionDescriptor::new(
+            "size-precision",
+            "Set the number of decimal places displayed in human-readable sizes, from 0 to 3.",
+            |parser, value| {
+                let Some(value) = value else {
+                    return Some(Output::Error("missing precision for option 'size-precision'".into()));
+                };
+
+                match value.parse() {
+                    Ok(precision @ 0..=3) => parser.arguments.size_precision = Some(precision),
+                    _ => return Some(Output::Error(format!("invalid size precision: {value}"))),
+                }
+
+                None
+            },
+        )
+        .argument("N"),
+    ),
+    Some(OptionDescriptor::new("bytes", "Display sizes as exact byte counts, even if human-readable.", |parser, _| {
+        parser.arguments.raw_sizes = true;
+
+        None
+    })),
+    Some(
+        OptionDescriptor::new("block-size", "Display sizes as counts of blocks of the given size.", self::parse_block_size)
+            .argument("SIZE"),
+    ),
+    Some(
+        OptionDescriptor::new(
+            "thousands",
+            "Separate the digits of sizes into groups of thousands.",
+            |parser, _| {
+                parser.arguments.group_thousands = true;
+
+                None
+            },
+        )
+        .negation(|parser, _| {
+            parser.arguments.group_thousands = false;
+
+            None
+        }),
+    ),
+    Some(
+        OptionDescriptor::new("numeric-uid-gid", "Display numeric owner identifiers instead of names.", |parser, _| {
+            parser.arguments.numeric_owner = true;
+
+            None
+        })
+        .short('n'),
+    ),
+    Some(
+        OptionDescriptor::new(
+            "columns",
+            "Display the given columns in the given order, overriding which details are displayed.",
+            self::parse_columns,
+        )
+        .argument("COLUMNS"),
+    ),
+    Some(
+        OptionDescriptor::new(
+            "field-separator",
+            "Set the separator displayed between detail columns.",
+            |parser, value| {
+                let Some(value) = value else {
+                    return Some(Output::Error("missing separator for option 'field-separator'".into()));
+                };
+
+                parser.arguments.field_separator = Some(value.into());
+
+                None
+            },
+        )
+        .argument("SEPARATOR"),
+    ),
+    None,
+    Some(OptionDescriptor::new("stdin", "Read the paths to list from standard input.", |parser, _| {
+        parser.read_stdin = true;
+
+        None
+    })),
+    Some(
+        OptionDescriptor::new("paths-from", "Read the paths to list from the given file, one per line.", |parser, value| {
+            let Some(value) = value else {
+                return Some(Output::Error("missing file for option 'paths-from'".into()));
+            };
+
+            match self::read_file_paths(value) {
+                Ok(paths) => parser.file_paths.extend(paths),
+                Err(error) => return Some(Output::Error(format!("failed to read paths from '{value}': {error}"))),
+            }
+
+            None
+        })
+        .aliases(&["paths-from-file"])
+        .argument("FILE"),
+    ),
+    Some(
+        OptionDescriptor::new("null", "Separate paths read from standard input with null bytes.", |parser, _| {
+            parser.null_separated = true;
+
+            None
+        })
+        .short('0'),
+    ),
+];
+
+/// Prints a help display.
+///
+/// # Errors
+///
+/// This function will return an error if the display could not be printed.
+fn print_help(arguments: &Arguments, error: bool) -> Result<()> {
     if error {
         self::write_help(arguments, &mut std::io::stderr(), error, OPTIONS)
     } else {
@@ -253,6 +1861,31 @@ fn print_help(arguments: &Arguments, error: bool) -> Result<()> {
     }
 }
 
+/// Prints the help display of the option with the given short or long name.
+///
+/// Returns an error listing every available option if no such option exists.
+fn print_option_help(arguments: &Arguments, name: &str) -> Output {
+    let name = name.trim_start_matches('-');
+    let mut characters = name.chars();
+    let option = match (characters.next(), characters.next()) {
+        (Some(short), None) => Opt::Short(short),
+        _ => Opt::Long(name),
+    };
+
+    let descriptors = OPTIONS.iter().flatten().filter(|descriptor| !descriptor.hidden);
+
+    if let Some(descriptor) = descriptors.clone().find(|descriptor| descriptor.matches(option)) {
+        self::write_help_option(arguments, &mut std::io::stdout(), false, descriptor)
+            .expect("failed to print help menu");
+
+        return Output::Exit;
+    }
+
+    let names = descriptors.map(|descriptor| descriptor.long).collect::<Vec<_>>();
+
+    Output::Error(format!("unknown option: {name}\navailable options: {}", names.join(", ")))
+}
+
 macro_rules! cprint {
     ($error:expr, $self:expr, $color:ident; $write:expr, $($body:tt)*) => {
         if $error {
@@ -280,7 +1913,7 @@ macro_rules! cprintln {
 /// This function will return an error if the display failed to be written.
 fn write_help<I>(arguments: &Arguments, f: &mut impl Write, error: bool, options: I) -> Result<()>
 where
-    I: IntoIterator<Item = &'static Option<HelpOption<'static>>>,
+    I: IntoIterator<Item = &'static Option<OptionDescriptor>>,
 {
     cprintln!(error, arguments, italic; f, "{}", env!("CARGO_PKG_DESCRIPTION"))?;
 
@@ -293,10 +1926,10 @@ where
     cprintln!(error, arguments, bold; f, "Options:")?;
 
     for option in options {
-        if let Some(option) = *option {
-            self::write_help_option(arguments, f, error, option)?;
-        } else {
-            f.write_all(b"\n")?;
+        match option {
+            Some(option) if option.hidden => {}
+            Some(option) => self::write_help_option(arguments, f, error, option)?,
+            None => f.write_all(b"\n")?,
         }
     }
 
@@ -312,7 +1945,7 @@ fn write_help_option(
     arguments: &Arguments,
     f: &mut impl Write,
     error: bool,
-    (short, long, description, values): HelpOption<'_>,
+    &OptionDescriptor { short, long, description, values, argument, .. }: &OptionDescriptor,
 ) -> Result<()> {
     /// The number of spaces to add to the front of the option listing.
     const START_PAD: &[u8] = b"  ";
@@ -335,13 +1968,20 @@ fn write_help_option(
         f.write_all(&b" ".repeat(SHORT_LEN))?;
     }
 
-    let long_len = long.chars().count();
+    let argument = argument.map_or_else(String::new, |argument| format!(" <{argument}>"));
+    let long_len = long.chars().count() + argument.chars().count();
 
     // Truncate if the option overflows.
     if long_len >= GAP_WIDTH {
-        cprint!(error, arguments, bright_cyan; f, "--{}...", &long[..GAP_WIDTH - 4])?;
+        let long = format!("{long}{argument}");
+        // Truncate on a character boundary, since slicing by byte could split a multibyte character.
+        let end = long.char_indices().nth(GAP_WIDTH - 4).map_or(long.len(), |(index, _)| index);
+
+        cprint!(error, arguments, bright_cyan; f, "--{}...", &long[..end])?;
     } else {
         cprint!(error, arguments, bright_cyan; f, "--{long}")?;
+
+        f.write_all(argument.as_bytes())?;
     }
 
     // Ensure at least one space is always printed betwixt the options and their descriptions.
@@ -349,11 +1989,47 @@ fn write_help_option(
 
     f.write_all(&b" ".repeat(spacing))?;
 
-    writeln!(f, "{description}")?;
+    self::write_wrapped::<DESCRIPTION_OFFSET>(f, description, layout::terminal_width())?;
 
     values.map_or(Ok(()), |values| self::write_help_option_values::<DESCRIPTION_OFFSET>(arguments, f, error, values))
 }
 
+/// Writes the given text into the formatter, breaking it on word boundaries so that lines fit within the given width.
+///
+/// The first line is assumed to already be indented, while continuation lines are indented to `OFFSET`.
+///
+/// # Errors
+///
+/// This function will return an error if the text failed to be written.
+fn write_wrapped<const OFFSET: usize>(f: &mut impl Write, text: &str, width: usize) -> Result<()> {
+    /// The narrowest that a line may be wrapped to, so that very small terminals still show whole words.
+    const MIN_LINE_WIDTH: usize = 20;
+
+    let line_width = width.saturating_sub(OFFSET).max(MIN_LINE_WIDTH);
+    let mut current_width = 0;
+
+    for word in text.split_whitespace() {
+        let word_width = word.chars().count();
+
+        if current_width > 0 && current_width + 1 + word_width > line_width {
+            f.write_all(b"\n")?;
+            f.write_all(&b" ".repeat(OFFSET))?;
+
+            current_width = 0;
+        } else if current_width > 0 {
+            f.write_all(b" ")?;
+
+            current_width += 1;
+        }
+
+        f.write_all(word.as_bytes())?;
+
+        current_width += word_width;
+    }
+
+    f.write_all(b"\n")
+}
+
 /// Writes a help display's option's values into the given formatter.
 ///
 /// # Errors
@@ -363,8 +2039,12 @@ fn write_help_option_values<const DESCRIPTION_OFFSET: usize>(
     arguments: &Arguments,
     f: &mut impl Write,
     error: bool,
-    (default, values): HelpOptionValues<'_>,
+    values: &[&str],
 ) -> Result<()> {
+    let Some(default) = values.first() else {
+        return Ok(());
+    };
+
     f.write_all(&b" ".repeat(DESCRIPTION_OFFSET))?;
 
     cprint!(error, arguments, bright_black; f, "-")?;
@@ -377,10 +2057,6 @@ fn write_help_option_values<const DESCRIPTION_OFFSET: usize>(
 
     cprintln!(error, arguments, bold; f, "{default}")?;
 
-    if values.is_empty() {
-        return Ok(());
-    }
-
     f.write_all(&b" ".repeat(DESCRIPTION_OFFSET))?;
 
     cprint!(error, arguments, bright_black; f, "-")?;
@@ -401,3 +2077,92 @@ fn write_help_option_values<const DESCRIPTION_OFFSET: usize>(
 
     f.write_all(b"\n")
 }
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+    use std::path::Path;
+    use std::time::SystemTime;
+
+    use getargs::Options;
+    use time::macros::datetime;
+    use time::UtcOffset;
+
+    use super::{Arguments, OptionDescriptor, Output};
+    use crate::sort::{HoistType, SortType};
+
+    /// Parses the given command-line arguments, panicking if they don't produce a set of arguments.
+    fn parse(arguments: &[&str]) -> Arguments {
+        match super::parse_arguments(Options::new(arguments.iter().copied())) {
+            Output::Arguments(arguments) => *arguments,
+            output => panic!("expected arguments, got {output:?}"),
+        }
+    }
+
+    #[test]
+    fn source_hoists_directories() {
+        assert_eq!(parse(&["--sort", "source"]).hoist_function, HoistType::Directories);
+        assert_eq!(parse(&["--sort", "source", "--hoist", "none"]).hoist_function, HoistType::None);
+        assert_eq!(parse(&["--hoist", "none", "--sort", "source"]).hoist_function, HoistType::None);
+    }
+
+    #[test]
+    fn later_sort_replaces_source_hoist() {
+        let arguments = parse(&["--sort", "source", "--sort", "name"]);
+
+        assert_eq!(arguments.hoist_function, HoistType::None);
+        assert_eq!(&*arguments.sort_functions, &[SortType::Name { case_sensitive: false }]);
+    }
+
+    #[test]
+    fn double_dash_ends_options() {
+        let arguments = parse(&["--", "-a", "--all"]);
+
+        assert_eq!(&*arguments.paths, &[Path::new("-a").into(), Path::new("--all").into()]);
+        assert!(!arguments.show_hidden);
+    }
+
+    #[test]
+    fn cutoffs() {
+        let hour = Duration::from_hours(1);
+        let offset = UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC);
+        let before = SystemTime::now();
+        let days = parse(&["--newer-than", "3d"]).newer_than.expect("the cutoff should be set");
+        let hours = parse(&["--older-than", "2h"]).older_than.expect("the cutoff should be set");
+        let after = SystemTime::now();
+
+        assert!((before - (hour * 72)..=after - (hour * 72)).contains(&days));
+        assert!((before - (hour * 2)..=after - (hour * 2)).contains(&hours));
+        assert_eq!(
+            parse(&["--newer-than", "2024-06-01"]).newer_than,
+            Some(SystemTime::from(datetime!(2024-06-01 0:00).assume_offset(offset)))
+        );
+        assert_eq!(
+            parse(&["--newer-than", "2024-06-01T12:30"]).newer_than,
+            Some(SystemTime::from(datetime!(2024-06-01 12:30).assume_offset(offset)))
+        );
+
+        for value in ["3x", "10M"] {
+            let output = super::parse_arguments(Options::new(["--newer-than", value].into_iter()));
+
+            assert!(matches!(output, Output::Error(_)), "'{value}' should be rejected");
+        }
+    }
+
+    #[test]
+    fn help_truncates_multibyte_names() -> std::io::Result<()> {
+        // The truncation point falls within the second byte of a character, which slicing by byte would panic on.
+        let long = format!("a{}", "é".repeat(25));
+        let option = OptionDescriptor::new(long.leak(), "Description.", |_, _| None);
+        let arguments = Arguments { color: Some(false), ..Arguments::default() };
+        let mut output = Vec::new();
+
+        super::write_help_option(&arguments, &mut output, false, &option)?;
+
+        let output = String::from_utf8_lossy(&output);
+
+        assert!(output.starts_with(&format!("      --a{}... Description.", "é".repeat(19))), "{output}");
+
+        Ok(())
+    }
+}
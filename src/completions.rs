@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+use std::io::{Result, Write};
+
+use crate::arguments::OptionDescriptor;
+
+/// The name of the program being completed.
+const PROGRAM: &str = env!("CARGO_PKG_NAME");
+
+/// Shells that completion scripts can be generated for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shell {
+    /// The Bourne Again shell.
+    Bash,
+    /// The Z shell.
+    Zsh,
+    /// The friendly interactive shell.
+    Fish,
+}
+
+impl Shell {
+    /// Writes a completion script for the given options.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the script could not be written.
+    pub(crate) fn write_completions<'a, W, I>(self, f: &mut W, options: I) -> Result<()>
+    where
+        W: Write,
+        I: IntoIterator<Item = &'a Option<OptionDescriptor>>,
+    {
+        let options = options.into_iter().flatten().filter(|option| !option.hidden);
+
+        match self {
+            Self::Bash => self::write_bash(f, options),
+            Self::Zsh => self::write_zsh(f, options),
+            Self::Fish => self::write_fish(f, options),
+        }
+    }
+}
+
+/// Writes a Bash completion script for the given options.
+///
+/// # Errors
+///
+/// This function will return an error if the script could not be written.
+fn write_bash<'a>(f: &mut impl Write, options: impl Iterator<Item = &'a OptionDescriptor>) -> Result<()> {
+    let mut flags = Vec::new();
+    let mut cases = Vec::new();
+
+    for &OptionDescriptor { short, long, values, argument, .. } in options {
+        let short = short.map(|short| format!("-{short}"));
+        let long = format!("--{long}");
+        let pattern = short.as_ref().map_or_else(|| long.clone(), |short| format!("{short}|{long}"));
+
+        if let Some(values) = values {
+            writeln!(cases, "        {pattern})")?;
+            writeln!(cases, "            COMPREPLY=($(compgen -W \"{}\" -- \"$cur\"))", values.join(" "))?;
+            writeln!(cases, "            return")?;
+            writeln!(cases, "            ;;")?;
+        } else if argument.is_some() {
+            writeln!(cases, "        {pattern})")?;
+            writeln!(cases, "            COMPREPLY=($(compgen -f -- \"$cur\"))")?;
+            writeln!(cases, "            return")?;
+            writeln!(cases, "            ;;")?;
+        }
+
+        flags.extend(short);
+        flags.push(long);
+    }
+
+    writeln!(f, "_{PROGRAM}() {{")?;
+    writeln!(f, "    local cur=\"${{COMP_WORDS[COMP_CWORD]}}\"")?;
+    writeln!(f, "    local prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"")?;
+    writeln!(f)?;
+    writeln!(f, "    case \"$prev\" in")?;
+    f.write_all(&cases)?;
+    writeln!(f, "    esac")?;
+    writeln!(f)?;
+    writeln!(f, "    if [[ \"$cur\" == -* ]]; then")?;
+    writeln!(f, "        COMPREPLY=($(compgen -W \"{}\" -- \"$cur\"))", flags.join(" "))?;
+    writeln!(f, "    fi")?;
+    writeln!(f, "}}")?;
+    writeln!(f)?;
+    writeln!(f, "complete -o default -F _{PROGRAM} {PROGRAM}")
+}
+
+/// Writes a Zsh completion script for the given options.
+///
+/// # Errors
+///
+/// This function will return an error if the script could not be written.
+fn write_zsh<'a>(f: &mut impl Write, options: impl Iterator<Item = &'a OptionDescriptor>) -> Result<()> {
+    writeln!(f, "#compdef {PROGRAM}")?;
+    writeln!(f)?;
+    writeln!(f, "_arguments -s \\")?;
+
+    for &OptionDescriptor { short, long, description, values, argument, .. } in options {
+        let description = description.replace('\'', "'\\''").replace('[', "\\[").replace(']', "\\]");
+        let values = match (values, argument) {
+            (Some(values), _) => format!(":value:({})", values.join(" ")),
+            (None, Some(argument)) => format!(":{}:_files", argument.to_lowercase()),
+            (None, None) => String::new(),
+        };
+
+        if let Some(short) = short {
+            writeln!(f, "    '(-{short} --{long})'{{-{short},--{long}}}'[{description}]{values}' \\")?;
+        } else {
+            writeln!(f, "    '--{long}[{description}]{values}' \\")?;
+        }
+    }
+
+    writeln!(f, "    '*:path:_files'")
+}
+
+/// Writes a Fish completion script for the given options.
+///
+/// # Errors
+///
+/// This function will return an error if the script could not be written.
+fn write_fish<'a>(f: &mut impl Write, options: impl Iterator<Item = &'a OptionDescriptor>) -> Result<()> {
+    for &OptionDescriptor { short, long, description, values, argument, .. } in options {
+        write!(f, "complete -c {PROGRAM}")?;
+
+        if let Some(short) = short {
+            write!(f, " -s {short}")?;
+        }
+
+        write!(f, " -l {long}")?;
+
+        if let Some(values) = values {
+            write!(f, " -x -a '{}'", values.join(" "))?;
+        } else if argument.is_some() {
+            write!(f, " -r -F")?;
+        }
+
+        writeln!(f, " -d '{}'", description.replace('\\', "\\\\").replace('\'', "\\'"))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+use std::io::{Result, Write};
+
+use crate::{arguments::Arguments, cwrite, Entry};
+
+use super::{Displayer, HasColor};
+
+/// Displays the number of entries within a directory.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChildCountDisplay<'ar> {
+    /// The program's arguments.
+    arguments: &'ar Arguments,
+}
+
+impl<'ar> ChildCountDisplay<'ar> {
+    /// The width of the displayed column, beyond which counts are allowed to overflow.
+    pub const WIDTH: usize = 5;
+
+    /// Creates a new [`ChildCountDisplay`].
+    #[must_use]
+    pub const fn new(arguments: &'ar Arguments) -> Self {
+        Self { arguments }
+    }
+}
+
+impl HasColor for ChildCountDisplay<'_> {
+    fn has_color(&self) -> Option<bool> {
+        self.arguments.color
+    }
+}
+
+impl Displayer for ChildCountDisplay<'_> {
+    fn show<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
+        if !entry.data.is_dir() {
+            return cwrite!(self, bright_black; f, "{:>width$}", "-", width = Self::WIDTH);
+        }
+
+        // Unreadable directories, such as those without permission, are marked rather than failing the listing.
+        match entry.path.read_dir() {
+            Ok(children) => cwrite!(self, bright_cyan; f, "{:>width$}", children.count(), width = Self::WIDTH),
+            Err(_) => cwrite!(self, bright_red; f, "{:>width$}", "?", width = Self::WIDTH),
+        }
+    }
+}
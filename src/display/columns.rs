@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+use std::io::{Result, Write};
+
+use crate::{arguments::Arguments, Entry};
+
+use super::{
+    ChildCountDisplay, Displayer, DynDisplayer, MimeDisplay, ModifiedDisplay, NameDisplay, OwnerDisplay,
+    PermissionsDisplay, SizeDisplay,
+};
+
+/// The kinds of columns that may be displayed for each entry.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColumnType {
+    /// The entry's permissions.
+    Permissions,
+    /// The entry's size.
+    Size,
+    /// The number of entries within the entry, if it's a directory.
+    Children,
+    /// The entry's modification date.
+    Modified,
+    /// The entry's owner.
+    Owner,
+    /// The entry's content type.
+    Mime,
+    /// The entry's name.
+    Name,
+}
+
+impl core::fmt::Display for ColumnType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Permissions => "permissions",
+            Self::Size => "size",
+            Self::Children => "children",
+            Self::Modified => "modified",
+            Self::Owner => "owner",
+            Self::Mime => "mime",
+            Self::Name => "name",
+        })
+    }
+}
+
+/// Displays an ordered list of columns, separated from each other by a separator.
+///
+/// # Examples
+///
+/// Doctests can't import the items of a binary crate, so this example isn't compiled.
+///
+/// ```ignore
+/// use crate::arguments::Arguments;
+/// use crate::display::{Columns, NameDisplay, SizeDisplay};
+///
+/// let arguments = Arguments::default();
+/// // Displays each entry's name, followed by its size.
+/// let columns = Columns::new(" | ").with_column(NameDisplay::new(&arguments)).with_column(SizeDisplay::new(&arguments));
+/// ```
+#[non_exhaustive]
+pub struct Columns<'ar> {
+    /// The displayed columns, in order.
+    columns: Vec<Box<dyn DynDisplayer + 'ar>>,
+    /// The separator displayed between each column.
+    separator: &'ar str,
+}
+
+impl<'ar> Columns<'ar> {
+    /// Creates a new, empty [`Columns`].
+    #[must_use]
+    pub const fn new(separator: &'ar str) -> Self {
+        Self { columns: Vec::new(), separator }
+    }
+
+    /// Creates a new [`Columns`] containing every column chosen by the given arguments, in the chosen order.
+    #[must_use]
+    pub fn row(arguments: &'ar Arguments) -> Self {
+        let mut columns = Self::new(arguments.field_separator.as_deref().unwrap_or(" "));
+
+        for column in &arguments.columns {
+            columns.push_type(arguments, *column);
+        }
+
+        columns
+    }
+
+    /// Creates a new [`Columns`] containing every detail column chosen by the given arguments, in the chosen order,
+    /// without the name column.
+    #[must_use]
+    pub fn details(arguments: &'ar Arguments) -> Self {
+        let mut columns = Self::new(arguments.field_separator.as_deref().unwrap_or(" "));
+
+        for column in arguments.columns.iter().filter(|column| **column != ColumnType::Name) {
+            columns.push_type(arguments, *column);
+        }
+
+        columns
+    }
+
+    /// Returns the separator displayed between each column.
+    #[must_use]
+    pub const fn separator(&self) -> &'ar str {
+        self.separator
+    }
+
+    /// Returns the number of columns.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns `true` if there are no columns.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+
+    /// Appends the given column after all existing columns.
+    pub fn push(&mut self, column: impl Displayer + 'ar) {
+        self.columns.push(Box::new(column));
+    }
+
+    /// Appends the displayer of the given column type after all existing columns.
+    pub fn push_type(&mut self, arguments: &'ar Arguments, column: ColumnType) {
+        match column {
+            ColumnType::Permissions => self.push(PermissionsDisplay::new(arguments)),
+            ColumnType::Size => self.push(SizeDisplay::new(arguments)),
+            ColumnType::Children => self.push(ChildCountDisplay::new(arguments)),
+            ColumnType::Modified => self.push(ModifiedDisplay::new(arguments)),
+            ColumnType::Owner => self.push(OwnerDisplay::new(arguments)),
+            ColumnType::Mime => self.push(MimeDisplay::new(arguments)),
+            ColumnType::Name => self.push(NameDisplay::new(arguments)),
+        }
+    }
+
+    /// Returns these columns with the given column appended after all existing columns.
+    #[must_use]
+    pub fn with_column(mut self, column: impl Displayer + 'ar) -> Self {
+        self.push(column);
+
+        self
+    }
+}
+
+impl core::fmt::Debug for Columns<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Columns")
+            .field("len", &self.len())
+            .field("separator", &self.separator)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Displayer for Columns<'_> {
+    fn show<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
+        for (index, column) in self.columns.iter().enumerate() {
+            if index > 0 {
+                f.write_all(self.separator.as_bytes())?;
+            }
+
+            column.show_dyn(f, entry)?;
+        }
+
+        Ok(())
+    }
+}
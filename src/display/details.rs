@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+use std::io::{Result, Write};
+
+use crate::{arguments::Arguments, Entry};
+
+use super::{Columns, Displayer, HasColor};
+
+/// Displays an entry's enabled detail columns, each followed by a separator.
+#[non_exhaustive]
+#[derive(Debug)]
+pub struct DetailsDisplay<'ar> {
+    /// The program's arguments.
+    arguments: &'ar Arguments,
+    /// The enabled detail columns.
+    columns: Columns<'ar>,
+}
+
+impl<'ar> DetailsDisplay<'ar> {
+    /// Creates a new [`DetailsDisplay`].
+    #[must_use]
+    pub fn new(arguments: &'ar Arguments) -> Self {
+        Self { arguments, columns: Columns::details(arguments) }
+    }
+}
+
+impl HasColor for DetailsDisplay<'_> {
+    fn has_color(&self) -> Option<bool> {
+        self.arguments.color
+    }
+}
+
+impl Displayer for DetailsDisplay<'_> {
+    fn show<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
+        if self.columns.is_empty() {
+            return Ok(());
+        }
+
+        self.columns.show(f, entry)?;
+
+        f.write_all(self.columns.separator().as_bytes())
+    }
+}
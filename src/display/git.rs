@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+use std::io::{Result, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use super::HasColor;
+use crate::{arguments::Arguments, cwrite};
+
+/// A summary of the status of a git repository.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GitStatus {
+    /// The checked out branch, or [`None`] if no branch is checked out.
+    pub branch: Option<Box<str>>,
+    /// The number of entries with unstaged changes.
+    pub modified: usize,
+    /// The number of entries with staged changes.
+    pub staged: usize,
+    /// The number of untracked entries.
+    pub untracked: usize,
+}
+
+impl GitStatus {
+    /// Reads the status of the repository containing the given directory.
+    ///
+    /// Returns [`None`] if the directory is not within a repository, or if git could not be run.
+    #[must_use]
+    pub fn read(directory: &Path) -> Option<Self> {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(directory)
+            .args(["status", "--porcelain=v2", "--branch"])
+            .stdin(Stdio::null())
+            .stderr(Stdio::null())
+            .output()
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        Some(Self::parse(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// Parses the output of `git status --porcelain=v2 --branch`.
+    fn parse(output: &str) -> Self {
+        let mut status = Self::default();
+
+        for line in output.lines() {
+            if let Some(branch) = line.strip_prefix("# branch.head ") {
+                status.branch = (branch != "(detached)").then(|| branch.into());
+
+                continue;
+            }
+
+            let mut fields = line.split(' ');
+
+            match (fields.next(), fields.next().map(str::as_bytes)) {
+                // Changed and renamed entries, whose fields begin with their staged and unstaged states.
+                (Some("1" | "2"), Some(&[staged, unstaged])) => {
+                    status.staged += usize::from(staged != b'.');
+                    status.modified += usize::from(unstaged != b'.');
+                }
+                // Unmerged entries, which need to be resolved before they can be staged.
+                (Some("u"), _) => status.modified += 1,
+                (Some("?"), _) => status.untracked += 1,
+                _ => {}
+            }
+        }
+
+        status
+    }
+}
+
+/// Displays a summary of the status of a git repository.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GitStatusDisplay<'ar> {
+    /// The program's arguments.
+    arguments: &'ar Arguments,
+}
+
+impl<'ar> GitStatusDisplay<'ar> {
+    /// Creates a new [`GitStatusDisplay`].
+    #[must_use]
+    pub const fn new(arguments: &'ar Arguments) -> Self {
+        Self { arguments }
+    }
+
+    /// Displays the given status.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the status could not be displayed.
+    pub fn show<W: Write>(&self, f: &mut W, status: &GitStatus) -> Result<()> {
+        if let Some(ref branch) = status.branch {
+            cwrite!(self, white; f, "branch ")?;
+            cwrite!(self, bright_magenta; f, "{branch}")?;
+        } else {
+            cwrite!(self, bright_magenta; f, "detached HEAD")?;
+        }
+
+        cwrite!(self, bright_black; f, ", ")?;
+        cwrite!(self, bold; f, "{}", status.modified)?;
+        cwrite!(self, bright_yellow; f, " modified")?;
+        cwrite!(self, bright_black; f, ", ")?;
+        cwrite!(self, bold; f, "{}", status.staged)?;
+        cwrite!(self, bright_green; f, " staged")?;
+        cwrite!(self, bright_black; f, ", ")?;
+        cwrite!(self, bold; f, "{}", status.untracked)?;
+        cwrite!(self, bright_red; f, " untracked")
+    }
+}
+
+impl HasColor for GitStatusDisplay<'_> {
+    fn has_color(&self) -> Option<bool> {
+        self.arguments.color
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GitStatus;
+
+    #[test]
+    fn porcelain_output_is_counted() {
+        let status = GitStatus::parse(concat!(
+            "# branch.oid 0123456789abcdef0123456789abcdef01234567\n",
+            "# branch.head main\n",
+            "# branch.upstream origin/main\n",
+            "# branch.ab +1 -0\n",
+            "1 .M N... 100644 100644 100644 0123456 0123456 src/unstaged.rs\n",
+            "1 M. N... 100644 100644 100644 0123456 89abcde src/staged.rs\n",
+            "1 MM N... 100644 100644 100644 0123456 89abcde src/both.rs\n",
+            "2 R. N... 100644 100644 100644 0123456 0123456 R100 src/renamed.rs\tsrc/original.rs\n",
+            "u UU N... 100644 100644 100644 100644 0123456 89abcde fedcba9 src/conflict.rs\n",
+            "? src/untracked.rs\n",
+            "? notes.txt\n",
+        ));
+
+        assert_eq!(status.branch.as_deref(), Some("main"));
+        assert_eq!(status.modified, 3);
+        assert_eq!(status.staged, 3);
+        assert_eq!(status.untracked, 2);
+    }
+
+    #[test]
+    fn detached_head_has_no_branch() {
+        let status = GitStatus::parse(concat!(
+            "# branch.oid 0123456789abcdef0123456789abcdef01234567\n",
+            "# branch.head (detached)\n",
+        ));
+
+        assert_eq!(status, GitStatus::default());
+    }
+}
@@ -16,8 +16,9 @@
 // see <https://www.gnu.org/licenses/>.
 
 use std::io::{Result, Write};
+use std::path::Path;
 
-use owo_colors::OwoColorize;
+use owo_colors::{OwoColorize, Stream, Style};
 
 use crate::{arguments::Arguments, cwrite, Entry};
 
@@ -29,13 +30,79 @@ use super::{Displayer, HasColor};
 pub struct HeaderDisplay<'ar> {
     /// The program's arguments.
     arguments: &'ar Arguments,
+    /// The number of entries listed beneath the header, if it should be displayed.
+    count: Option<usize>,
 }
 
 impl<'ar> HeaderDisplay<'ar> {
     /// Creates a new [`HeaderDisplay`].
     #[must_use]
     pub const fn new(arguments: &'ar Arguments) -> Self {
-        Self { arguments }
+        Self { arguments, count: None }
+    }
+
+    /// Displays the given number of listed entries after the header's path.
+    #[must_use]
+    pub const fn with_count(mut self, count: usize) -> Self {
+        self.count = Some(count);
+
+        self
+    }
+
+    /// Displays the given text in bold, colored like a directory, within the given writer.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the text could not be displayed.
+    fn show_bold<W: Write>(&self, f: &mut W, text: &str) -> Result<()> {
+        let style = Style::new().bright_blue().bold();
+
+        // Bold is a style rather than a color, so it's applied here instead of through `cwrite!`.
+        match self.has_color() {
+            Some(false) => f.write_all(text.as_bytes()),
+            Some(true) => write!(f, "{}", text.style(style)),
+            None => write!(f, "{}", text.if_supports_color(Stream::Stdout, |v| v.style(style))),
+        }
+    }
+
+    /// Displays a header containing the given label.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the label could not be displayed.
+    pub fn show_label<W: Write>(&self, f: &mut W, label: &str) -> Result<()> {
+        self.show_bold(f, label)?;
+
+        f.write_all(b":")
+    }
+
+    /// Displays a header containing the given path, coloring each component like a directory and dimming the
+    /// separators between them.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the path could not be displayed.
+    pub fn show_path<W: Write>(&self, f: &mut W, path: &Path) -> Result<()> {
+        let path = path.to_string_lossy();
+
+        // Each segment is a component followed by its separators, either of which may be empty at the path's ends.
+        for segment in path.split_inclusive(std::path::is_separator) {
+            let component = segment.trim_end_matches(std::path::is_separator);
+            let separator = &segment[component.len()..];
+
+            if !component.is_empty() {
+                self.show_bold(f, component)?;
+            }
+            if !separator.is_empty() {
+                cwrite!(self, bright_black; f, "{separator}")?;
+            }
+        }
+
+        if let Some(count) = self.count {
+            cwrite!(self, bright_black; f, " ({count})")?;
+        }
+
+        f.write_all(b":")
     }
 }
 
@@ -47,8 +114,38 @@ impl HasColor for HeaderDisplay<'_> {
 
 impl Displayer for HeaderDisplay<'_> {
     fn show<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
-        cwrite!(self, bright_blue; f, "{}", entry.path.to_string_lossy().bold())?;
+        self.show_path(f, &entry.path)
+    }
+}
 
-        f.write_all(b":")
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::HeaderDisplay;
+    use crate::arguments::Arguments;
+
+    #[test]
+    fn label_without_color() -> std::io::Result<()> {
+        let arguments = Arguments { color: Some(false), ..Arguments::default() };
+        let mut output = Vec::new();
+
+        HeaderDisplay::new(&arguments).show_label(&mut output, "Directories")?;
+
+        assert_eq!(output, b"Directories:");
+
+        Ok(())
+    }
+
+    #[test]
+    fn path_without_color() -> std::io::Result<()> {
+        let arguments = Arguments { color: Some(false), ..Arguments::default() };
+        let mut output = Vec::new();
+
+        HeaderDisplay::new(&arguments).with_count(2).show_path(&mut output, Path::new("/tmp//rs/"))?;
+
+        assert_eq!(output, b"/tmp//rs/ (2):");
+
+        Ok(())
     }
 }
@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+use std::io::{Result, Write};
+
+use crate::{arguments::Arguments, cwrite, Entry};
+
+use super::{Displayer, HasColor};
+
+/// The content types guessed for each recognized file extension.
+const CONTENT_TYPES: &[(&str, &str)] = &[
+    ("7z", "application/x-7z-compressed"),
+    ("aac", "audio/aac"),
+    ("avi", "video/x-msvideo"),
+    ("bmp", "image/bmp"),
+    ("bz2", "application/x-bzip2"),
+    ("c", "text/x-c"),
+    ("css", "text/css"),
+    ("csv", "text/csv"),
+    ("doc", "application/msword"),
+    ("epub", "application/epub+zip"),
+    ("flac", "audio/flac"),
+    ("gif", "image/gif"),
+    ("gz", "application/gzip"),
+    ("h", "text/x-c"),
+    ("htm", "text/html"),
+    ("html", "text/html"),
+    ("ico", "image/vnd.microsoft.icon"),
+    ("jpeg", "image/jpeg"),
+    ("jpg", "image/jpeg"),
+    ("js", "text/javascript"),
+    ("json", "application/json"),
+    ("md", "text/markdown"),
+    ("mkv", "video/x-matroska"),
+    ("mp3", "audio/mpeg"),
+    ("mp4", "video/mp4"),
+    ("ogg", "audio/ogg"),
+    ("otf", "font/otf"),
+    ("pdf", "application/pdf"),
+    ("png", "image/png"),
+    ("py", "text/x-python"),
+    ("rs", "text/x-rust"),
+    ("sh", "application/x-sh"),
+    ("svg", "image/svg+xml"),
+    ("tar", "application/x-tar"),
+    ("tif", "image/tiff"),
+    ("tiff", "image/tiff"),
+    ("toml", "application/toml"),
+    ("ttf", "font/ttf"),
+    ("txt", "text/plain"),
+    ("wasm", "application/wasm"),
+    ("wav", "audio/wav"),
+    ("webm", "video/webm"),
+    ("webp", "image/webp"),
+    ("woff", "font/woff"),
+    ("woff2", "font/woff2"),
+    ("xml", "application/xml"),
+    ("yaml", "application/yaml"),
+    ("yml", "application/yaml"),
+    ("zip", "application/zip"),
+];
+
+/// Displays an entry's content type, as guessed from its extension.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MimeDisplay<'ar> {
+    /// The program's arguments.
+    arguments: &'ar Arguments,
+}
+
+impl<'ar> MimeDisplay<'ar> {
+    /// The width of the displayed column, which fits the longest known content type.
+    pub const WIDTH: usize = {
+        let mut width = 0;
+        let mut index = 0;
+
+        while index < CONTENT_TYPES.len() {
+            if CONTENT_TYPES[index].1.len() > width {
+                width = CONTENT_TYPES[index].1.len();
+            }
+
+            index += 1;
+        }
+
+        width
+    };
+
+    /// Creates a new [`MimeDisplay`].
+    #[must_use]
+    pub const fn new(arguments: &'ar Arguments) -> Self {
+        Self { arguments }
+    }
+
+    /// Returns the guessed content type of the given entry, if its extension is recognized.
+    fn get_content_type(entry: &Entry) -> Option<&'static str> {
+        if entry.data.is_dir() {
+            return None;
+        }
+
+        let extension = entry.path.extension()?.to_str()?;
+
+        CONTENT_TYPES
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(extension))
+            .map(|(_, content_type)| *content_type)
+    }
+}
+
+impl HasColor for MimeDisplay<'_> {
+    fn has_color(&self) -> Option<bool> {
+        self.arguments.color
+    }
+}
+
+impl Displayer for MimeDisplay<'_> {
+    fn show<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
+        if let Some(content_type) = Self::get_content_type(entry) {
+            cwrite!(self, bright_yellow; f, "{content_type:<width$}", width = Self::WIDTH)
+        } else {
+            cwrite!(self, bright_black; f, "{:<width$}", "-", width = Self::WIDTH)
+        }
+    }
+}
@@ -19,17 +19,34 @@ extern crate alloc;
 
 use std::io::Write;
 
+pub use self::children::ChildCountDisplay;
+pub use self::columns::{ColumnType, Columns};
+pub use self::details::DetailsDisplay;
+pub use self::git::{GitStatus, GitStatusDisplay};
 pub use self::header::HeaderDisplay;
-pub use self::modified::ModifiedDisplay;
-pub use self::name::NameDisplay;
+pub use self::mime::MimeDisplay;
+pub use self::modified::{ModifiedDisplay, TimeStyle};
+pub use self::name::{IndicatorStyle, NameDisplay};
 pub use self::owner::OwnerDisplay;
 pub use self::permissions::PermissionsDisplay;
 pub use self::size::SizeDisplay;
+pub use self::summary::{AgeCutoffs, Summary, SummaryDisplay};
+pub use self::tabular::{TabularDisplay, TabularFormat};
 
 use crate::Entry;
 
+/// Defines the child count display.
+mod children;
+/// Defines the columns display.
+mod columns;
+/// Defines the details display.
+mod details;
+/// Defines the git status display.
+mod git;
 /// Defines the header display.
 mod header;
+/// Defines the content type display.
+mod mime;
 /// Defines the modified display.
 mod modified;
 /// Defines the name display.
@@ -40,6 +57,10 @@ mod owner;
 mod permissions;
 /// Defines the size display.
 mod size;
+/// Defines the summary display.
+mod summary;
+/// Defines the tabular display.
+mod tabular;
 
 /// A type that determines whether to display using color.
 pub trait HasColor {
@@ -72,6 +93,24 @@ impl<T: Displayer> Displayer for &T {
     }
 }
 
+/// An object-safe form of [`Displayer`], allowing differing displayers to be stored together.
+///
+/// This is implemented for every [`Displayer`], and should not need to be implemented directly.
+pub trait DynDisplayer {
+    /// Displays an entry.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the entry could not be displayed.
+    fn show_dyn(&self, f: &mut dyn Write, entry: &Entry) -> std::io::Result<()>;
+}
+
+impl<T: Displayer> DynDisplayer for T {
+    fn show_dyn(&self, mut f: &mut dyn Write, entry: &Entry) -> std::io::Result<()> {
+        <T as Displayer>::show(self, &mut f, entry)
+    }
+}
+
 /// Writes a format string to standard output, optionally using color.
 ///
 /// # Examples
@@ -15,9 +15,11 @@
 // You should have received a copy of the GNU Affero General Public License along with rs. If not,
 // see <https://www.gnu.org/licenses/>.
 
-use std::io::{Result, Write};
+use core::time::Duration;
+use std::io::{Error, ErrorKind, Result, Write};
+use std::time::SystemTime;
 
-use time::format_description::FormatItem;
+use time::format_description::{FormatItem, OwnedFormatItem};
 use time::{OffsetDateTime, UtcOffset};
 
 use crate::arguments::Arguments;
@@ -35,20 +37,117 @@ const MACHINE_FORMAT: &[FormatItem] = time::macros::format_description!(
     version = 2,
     "[year]-[month padding:zero]-[day padding:zero] [hour padding:zero repr:24]:[minute padding:zero]"
 );
+/// A short ISO 8601 format for recent timestamps, omitting the year.
+const ISO_RECENT_FORMAT: &[FormatItem] = time::macros::format_description!(
+    version = 2,
+    "[month padding:zero]-[day padding:zero] [hour padding:zero repr:24]:[minute padding:zero]"
+);
+/// A short ISO 8601 format for older timestamps, omitting the time and padded to the width of the recent format.
+const ISO_OLD_FORMAT: &[FormatItem] =
+    time::macros::format_description!(version = 2, "[year]-[month padding:zero]-[day padding:zero] ");
+/// A complete ISO 8601 format, including seconds, subseconds, and the offset.
+const FULL_ISO_FORMAT: &[FormatItem] = time::macros::format_description!(
+    version = 2,
+    "[year]-[month padding:zero]-[day padding:zero] [hour padding:zero repr:24]:[minute padding:zero]:[second \
+     padding:zero].[subsecond digits:9] [offset_hour sign:mandatory][offset_minute]"
+);
+/// The age beyond which the short ISO 8601 format includes a timestamp's year rather than its time.
+const ISO_RECENT_AGE: Duration = Duration::from_hours(24 * 182);
+/// The units used to describe a timestamp's relative age, from largest to smallest, along with their length in seconds.
+const RELATIVE_UNITS: [(&str, u64); 6] = [
+    ("year", 60 * 60 * 24 * 365),
+    ("month", 60 * 60 * 24 * 30),
+    ("week", 60 * 60 * 24 * 7),
+    ("day", 60 * 60 * 24),
+    ("hour", 60 * 60),
+    ("minute", 60),
+];
+/// The width that relative ages are aligned to, which fits most descriptions, such as `59 minutes ago`.
+const RELATIVE_WIDTH: usize = 14;
+
+/// The styles that timestamps may be displayed in.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum TimeStyle {
+    /// A human-friendly format if human-readable formats are enabled, otherwise the same format as [`Self::LongIso`].
+    #[default]
+    Default,
+    /// A short ISO 8601 format, which includes the time of recent timestamps and the year of older timestamps.
+    Iso,
+    /// An ISO 8601 format, including the date, hour, and minute.
+    LongIso,
+    /// A complete ISO 8601 format, including seconds, subseconds, and the offset.
+    FullIso,
+    /// The timestamp's age relative to the current time, such as `3 hours ago`.
+    Relative,
+    /// A custom format description.
+    Custom(OwnedFormatItem),
+}
 
-/// Display's an entry's modification date.
+impl core::fmt::Display for TimeStyle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Default => f.write_str("default"),
+            Self::Iso => f.write_str("iso"),
+            Self::LongIso => f.write_str("long-iso"),
+            Self::FullIso => f.write_str("full-iso"),
+            Self::Relative => f.write_str("relative"),
+            Self::Custom(_) => f.write_str("custom"),
+        }
+    }
+}
+
+/// The ages below which timestamps are considered to be recent, from most to least recent.
+const AGE_STEPS: [Duration; 3] = [Duration::from_hours(1), Duration::from_hours(24), Duration::from_hours(24 * 30)];
+
+/// Display's an entry's selected timestamp, which is its modification date by default.
 #[non_exhaustive]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct ModifiedDisplay<'ar> {
     /// The program's arguments.
     arguments: &'ar Arguments,
+    /// The offset that timestamps are displayed in, or [`None`] to display them in UTC.
+    offset: Option<UtcOffset>,
+    /// The time that timestamps' ages are measured from.
+    now: SystemTime,
 }
 
 impl<'ar> ModifiedDisplay<'ar> {
-    /// Creates a new [`ModifiedDisplay`].
+    /// Creates a new [`ModifiedDisplay`] that displays timestamps in the local offset, if it can be determined.
     #[must_use]
-    pub const fn new(arguments: &'ar Arguments) -> Self {
-        Self { arguments }
+    pub fn new(arguments: &'ar Arguments) -> Self {
+        Self::with_clock(arguments, UtcOffset::current_local_offset().ok(), SystemTime::now())
+    }
+
+    /// Creates a new [`ModifiedDisplay`] that displays timestamps in the given offset, or in UTC if it is [`None`],
+    /// measuring their ages from the given time.
+    ///
+    /// This allows the displayed output to be made independent of the system's time zone and clock.
+    #[must_use]
+    pub const fn with_clock(arguments: &'ar Arguments, offset: Option<UtcOffset>, now: SystemTime) -> Self {
+        Self { arguments, offset, now }
+    }
+
+    /// Returns the position of the given timestamp on the age gradient, where `0` is the most recent.
+    ///
+    /// Timestamps from the future are considered to be the most recent.
+    fn get_age_step(&self, time: SystemTime) -> usize {
+        let age = self.now.duration_since(time).unwrap_or_default();
+
+        AGE_STEPS.iter().position(|step| age < *step).unwrap_or(AGE_STEPS.len())
+    }
+
+    /// Returns a description of the given timestamp's age, such as `3 hours ago`.
+    ///
+    /// Timestamps from the future are described as being from just now.
+    fn get_relative_age(&self, time: SystemTime) -> String {
+        let seconds = self.now.duration_since(time).unwrap_or_default().as_secs();
+        let unit = RELATIVE_UNITS.iter().find(|(_, length)| seconds >= *length);
+
+        let Some((unit, length)) = unit else { return format!("{:>RELATIVE_WIDTH$}", "just now") };
+        let count = seconds / length;
+        let plural = if count == 1 { "" } else { "s" };
+
+        format!("{:>RELATIVE_WIDTH$}", format!("{count} {unit}{plural} ago"))
     }
 }
 
@@ -60,14 +159,115 @@ impl HasColor for ModifiedDisplay<'_> {
 
 impl Displayer for ModifiedDisplay<'_> {
     fn show<W: Write>(&self, f: &mut W, entry: &crate::Entry) -> Result<()> {
-        let mut time = OffsetDateTime::from(entry.data.modified()?);
+        let time = match self.arguments.time_field.get(&entry.data) {
+            // Mirror the sorting fallback for file systems that don't record creation dates.
+            Err(error) if error.kind() == ErrorKind::Unsupported => entry.data.modified()?,
+            time => time?,
+        };
+        let step = if self.arguments.time_scale { self.get_age_step(time) } else { 1 };
+        let mut date = OffsetDateTime::from(time);
+
+        if let Some(offset) = self.offset {
+            date = date.to_offset(offset);
+        }
 
-        if let Ok(offset) = UtcOffset::current_local_offset() {
-            time = time.to_offset(offset);
+        let format = |format| date.format(format).expect("the compiled format is incorrectly defined");
+        let time = match self.arguments.time_style {
+            TimeStyle::Default if self.arguments.human_readable => format(HUMAN_FORMAT),
+            TimeStyle::Default | TimeStyle::LongIso => format(MACHINE_FORMAT),
+            TimeStyle::Iso if self.now.duration_since(time).is_ok_and(|age| age < ISO_RECENT_AGE) => {
+                format(ISO_RECENT_FORMAT)
+            }
+            TimeStyle::Iso => format(ISO_OLD_FORMAT),
+            TimeStyle::FullIso => format(FULL_ISO_FORMAT),
+            TimeStyle::Relative => self.get_relative_age(time),
+            // Custom formats are validated while parsing, but may still request components that can't be displayed.
+            TimeStyle::Custom(ref custom) => date.format(custom).map_err(Error::other)?,
+        };
+
+        match step {
+            0 => cwrite!(self, bright_white; f, "{time}"),
+            1 => cwrite!(self, bright_blue; f, "{time}"),
+            2 => cwrite!(self, blue; f, "{time}"),
+            _ => cwrite!(self, bright_black; f, "{time}"),
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use core::time::Duration;
+    use std::fs::File;
+    use std::io::Result;
+    use std::time::SystemTime;
+
+    use time::macros::{datetime, offset};
+    use time::UtcOffset;
+
+    use super::{ModifiedDisplay, TimeStyle};
+    use crate::arguments::Arguments;
+    use crate::display::Displayer;
+    use crate::Entry;
+
+    /// Displays the modification date of a file modified at `2024-03-01 12:34:56.5` UTC, as seen from the given offset
+    /// once the given amount of time has passed.
+    fn show(arguments: Arguments, offset: Option<UtcOffset>, elapsed: Duration) -> Result<String> {
+        /// Distinguishes the files created by tests running in parallel.
+        static FILES: AtomicUsize = AtomicUsize::new(0);
+
+        let modified = SystemTime::from(datetime!(2024-03-01 12:34:56.5 UTC));
+        let path = std::env::temp_dir().join(format!(
+            "rs-modified-{}-{}",
+            std::process::id(),
+            FILES.fetch_add(1, Ordering::Relaxed)
+        ));
+        let file = File::create(&path)?;
+
+        file.set_modified(modified)?;
+
+        let entry = Entry::new(path.clone(), file.metadata()?);
+        let arguments = Arguments { color: Some(false), ..arguments };
+        let mut output = Vec::new();
+
+        std::fs::remove_file(&path)?;
+
+        ModifiedDisplay::with_clock(&arguments, offset, modified + elapsed).show(&mut output, &entry)?;
+
+        String::from_utf8(output).map_err(std::io::Error::other)
+    }
+
+    /// Returns arguments that display timestamps in the given style.
+    fn style(time_style: TimeStyle) -> Arguments {
+        Arguments { time_style, ..Arguments::default() }
+    }
+
+    #[test]
+    fn offsets() -> Result<()> {
+        let day = Duration::from_hours(24);
+
+        assert_eq!(show(Arguments::default(), None, day)?, "2024-03-01 12:34");
+        assert_eq!(show(Arguments::default(), Some(offset!(+2)), day)?, "2024-03-01 14:34");
+        assert_eq!(show(Arguments::default(), Some(offset!(-5:30)), day)?, "2024-03-01 07:04");
+        assert_eq!(show(style(TimeStyle::FullIso), Some(offset!(+2)), day)?, "2024-03-01 14:34:56.500000000 +0200");
+
+        let human = Arguments { human_readable: true, ..Arguments::default() };
+
+        assert_eq!(show(human, Some(offset!(+2)), day)?, " 1 Mar '24 14:34");
+
+        Ok(())
+    }
+
+    #[test]
+    fn ages() -> Result<()> {
+        let hour = Duration::from_hours(1);
 
-        let format = if self.arguments.human_readable { HUMAN_FORMAT } else { MACHINE_FORMAT };
+        assert_eq!(show(style(TimeStyle::Iso), None, hour)?, "03-01 12:34");
+        assert_eq!(show(style(TimeStyle::Iso), None, hour * 24 * 365)?, "2024-03-01 ");
+        assert_eq!(show(style(TimeStyle::Relative), None, Duration::from_secs(30))?, "      just now");
+        assert_eq!(show(style(TimeStyle::Relative), None, hour * 3)?, "   3 hours ago");
+        assert_eq!(show(style(TimeStyle::Relative), None, hour * 24)?, "     1 day ago");
 
-        cwrite!(self, bright_blue; f, "{}", time.format(format).expect("the compiled format is incorrectly defined"))
+        Ok(())
     }
 }
@@ -19,14 +19,104 @@ extern crate alloc;
 
 use core::fmt::Display;
 use std::io::{Result, Write};
-use std::path::MAIN_SEPARATOR;
+use std::path::{MAIN_SEPARATOR, MAIN_SEPARATOR_STR};
 
 use is_executable::IsExecutable;
 
 use super::{Displayer, HasColor};
 use crate::arguments::Arguments;
+use crate::glyphs::Glyphs;
 use crate::{cwrite, Entry};
 
+/// Categories of files that are colored distinctly, as determined by their extensions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum FileCategory {
+    /// Image files.
+    Image,
+    /// Video files.
+    Video,
+    /// Audio files.
+    Audio,
+    /// Compressed or archived files.
+    Archive,
+    /// Source code files.
+    Source,
+    /// Text documents.
+    Document,
+}
+
+/// The category of files with each extension.
+const FILE_CATEGORIES: &[(&str, FileCategory)] = &[
+    ("bmp", FileCategory::Image),
+    ("gif", FileCategory::Image),
+    ("ico", FileCategory::Image),
+    ("jpeg", FileCategory::Image),
+    ("jpg", FileCategory::Image),
+    ("png", FileCategory::Image),
+    ("svg", FileCategory::Image),
+    ("tif", FileCategory::Image),
+    ("tiff", FileCategory::Image),
+    ("webp", FileCategory::Image),
+    ("avi", FileCategory::Video),
+    ("mkv", FileCategory::Video),
+    ("mov", FileCategory::Video),
+    ("mp4", FileCategory::Video),
+    ("webm", FileCategory::Video),
+    ("aac", FileCategory::Audio),
+    ("flac", FileCategory::Audio),
+    ("mp3", FileCategory::Audio),
+    ("ogg", FileCategory::Audio),
+    ("wav", FileCategory::Audio),
+    ("7z", FileCategory::Archive),
+    ("bz2", FileCategory::Archive),
+    ("gz", FileCategory::Archive),
+    ("rar", FileCategory::Archive),
+    ("tar", FileCategory::Archive),
+    ("xz", FileCategory::Archive),
+    ("zip", FileCategory::Archive),
+    ("zst", FileCategory::Archive),
+    ("c", FileCategory::Source),
+    ("cpp", FileCategory::Source),
+    ("go", FileCategory::Source),
+    ("h", FileCategory::Source),
+    ("java", FileCategory::Source),
+    ("js", FileCategory::Source),
+    ("py", FileCategory::Source),
+    ("rs", FileCategory::Source),
+    ("sh", FileCategory::Source),
+    ("toml", FileCategory::Source),
+    ("ts", FileCategory::Source),
+    ("md", FileCategory::Document),
+    ("pdf", FileCategory::Document),
+    ("rst", FileCategory::Document),
+    ("txt", FileCategory::Document),
+];
+
+/// The styles of the indicators displayed after entry names.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IndicatorStyle {
+    /// Do not display indicators.
+    None,
+    /// Display a trailing separator after directory names.
+    Slash,
+    /// Display a trailing separator after directory names, and an asterisk after executable names.
+    #[default]
+    Executable,
+    /// Display a trailing separator after directory names, and a character classifying other kinds of entries.
+    Classify,
+}
+
+impl core::fmt::Display for IndicatorStyle {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::None => "none",
+            Self::Slash => "slash",
+            Self::Executable => "executable",
+            Self::Classify => "classify",
+        })
+    }
+}
+
 /// Displays an entry's name.
 #[non_exhaustive]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -41,7 +131,179 @@ impl<'ar> NameDisplay<'ar> {
     /// Creates a new [`NameDisplay`].
     #[must_use]
     pub const fn new(arguments: &'ar Arguments) -> Self {
-        Self { arguments, trim_file_paths: true }
+        // Merged listings contain entries from several directories, so their paths are kept to tell them apart.
+        Self { arguments, trim_file_paths: !arguments.full_path && !arguments.merge }
+    }
+
+    /// Returns the entry's path relative to the base directory given by `--relative-to`, if it is within it.
+    fn relative_name(&self, entry: &Entry) -> Option<String> {
+        let base = self.arguments.relative_to.as_deref()?;
+        let path = std::path::absolute(&entry.path).ok()?;
+        let relative = path.strip_prefix(base).ok()?;
+
+        (!relative.as_os_str().is_empty()).then(|| relative.to_string_lossy().into_owned())
+    }
+
+    /// Returns the entry's name, or its full path if paths are not being trimmed.
+    fn path_name(&self, entry: &Entry) -> String {
+        if self.trim_file_paths {
+            let os_name = entry.path.file_name().unwrap_or(entry.path.as_os_str());
+
+            os_name.to_string_lossy().into_owned()
+        } else if self.arguments.full_path {
+            std::path::absolute(&entry.path)
+                .unwrap_or_else(|_| entry.path.clone())
+                .to_string_lossy()
+                .into_owned()
+        } else {
+            entry.path.to_string_lossy().into_owned()
+        }
+    }
+
+    /// Truncates the given name to at most `width` characters, marking the removed characters with an ellipsis.
+    ///
+    /// The name's extension is preserved if there is room for it, and the width is measured before any color is applied.
+    fn truncate(&self, name: &str, width: usize) -> String {
+        let length = name.chars().count();
+
+        if length <= width {
+            return name.to_owned();
+        }
+
+        // The ellipsis may be several characters wide, in which case it's shortened to fit very narrow widths.
+        let ellipsis = Glyphs::new(self.arguments).ellipsis.chars().take(width).collect::<String>();
+        let ellipsis_length = ellipsis.chars().count();
+
+        // A leading dot marks a hidden file rather than an extension.
+        let extension = name.rfind('.').filter(|index| *index > 0).map(|index| &name[index..]);
+        let suffix = extension
+            .filter(|extension| extension.chars().count() + ellipsis_length < width)
+            .unwrap_or_default();
+        let kept = width.saturating_sub(suffix.chars().count() + ellipsis_length);
+
+        let mut output = name.chars().take(kept).collect::<String>();
+
+        output.push_str(&ellipsis);
+        output.push_str(suffix);
+        output
+    }
+
+    /// Wraps the given name in double quotes, escaping any quotes within it alongside everything escaped by
+    /// [`escape`](Self::escape), so that both modes display control characters identically.
+    fn quote(name: &str) -> String {
+        format!("\"{}\"", Self::escape(name).replace('"', "\\\""))
+    }
+
+    /// Escapes any backslashes and non-printable characters within the given name with C-style backslash escapes.
+    ///
+    /// Characters without a named escape are written as the octal value of each of their bytes, such as `\033`.
+    fn escape(name: &str) -> String {
+        let mut output = String::with_capacity(name.len());
+
+        for character in name.chars() {
+            match character {
+                '\\' => output.push_str("\\\\"),
+                '\x07' => output.push_str("\\a"),
+                '\x08' => output.push_str("\\b"),
+                '\t' => output.push_str("\\t"),
+                '\n' => output.push_str("\\n"),
+                '\x0B' => output.push_str("\\v"),
+                '\x0C' => output.push_str("\\f"),
+                '\r' => output.push_str("\\r"),
+                character if character.is_control() => {
+                    for byte in character.encode_utf8(&mut [0; 4]).bytes() {
+                        output.push('\\');
+                        output.extend([byte >> 6, (byte >> 3) & 0o7, byte & 0o7].map(|digit| char::from(b'0' + digit)));
+                    }
+                }
+                character => output.push(character),
+            }
+        }
+
+        output
+    }
+
+    /// Returns the indicator displayed after the given entry's name, if any.
+    ///
+    /// Classified symbolic links are only marked when their targets aren't displayed, as targets have their own
+    /// indicators.
+    fn indicator(&self, entry: &Entry) -> Option<&'static str> {
+        let indicator = match self.arguments.indicator_style {
+            IndicatorStyle::None => None,
+            IndicatorStyle::Slash => entry.data.is_dir().then_some(MAIN_SEPARATOR_STR),
+            IndicatorStyle::Executable if entry.data.is_dir() => Some(MAIN_SEPARATOR_STR),
+            IndicatorStyle::Executable => (!entry.data.is_symlink() && entry.path.is_executable()).then_some("*"),
+            IndicatorStyle::Classify if entry.data.is_symlink() => {
+                (!self.arguments.show_symlinks || self.arguments.compact_symlinks).then_some("@")
+            }
+            IndicatorStyle::Classify if entry.data.is_dir() => Some(MAIN_SEPARATOR_STR),
+            IndicatorStyle::Classify if entry.path.is_executable() => Some("*"),
+            IndicatorStyle::Classify => Self::special_indicator(entry),
+        }?;
+
+        match indicator {
+            MAIN_SEPARATOR_STR if self.arguments.no_dir_slash => None,
+            "*" if self.arguments.no_exec_suffix => None,
+            _ => Some(indicator),
+        }
+    }
+
+    /// Returns the indicator classifying the given entry if it's a named pipe or a socket.
+    #[cfg(target_family = "unix")]
+    fn special_indicator(entry: &Entry) -> Option<&'static str> {
+        use std::os::unix::fs::FileTypeExt;
+
+        let file_type = entry.data.file_type();
+
+        if file_type.is_fifo() {
+            Some("|")
+        } else if file_type.is_socket() {
+            Some("=")
+        } else {
+            None
+        }
+    }
+
+    /// Returns the indicator classifying the given entry if it's a named pipe or a socket.
+    ///
+    /// Neither can be identified outside of Unix, so this always returns [`None`] elsewhere.
+    #[cfg(not(target_family = "unix"))]
+    const fn special_indicator(_: &Entry) -> Option<&'static str> {
+        None
+    }
+
+    /// Displays the indicator of the given entry within the given writer, if it has one.
+    ///
+    /// Directory separators share the color of the directory's name.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the indicator fails to display.
+    fn show_indicator<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
+        let Some(indicator) = self.indicator(entry) else { return Ok(()) };
+
+        if !entry.data.is_dir() {
+            cwrite!(self, white; f, "{indicator}")
+        } else if Self::is_hidden(entry) {
+            cwrite!(self, blue; f, "{indicator}")
+        } else {
+            cwrite!(self, bright_blue; f, "{indicator}")
+        }
+    }
+
+    /// Returns the category of the given entry, as determined by its extension, if it has a recognized one.
+    fn file_category(entry: &Entry) -> Option<FileCategory> {
+        let extension = entry.path.extension()?.to_str()?;
+
+        FILE_CATEGORIES
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(extension))
+            .map(|(_, category)| *category)
+    }
+
+    /// Returns whether the given entry is hidden, regardless of how much of its path is displayed.
+    fn is_hidden(entry: &Entry) -> bool {
+        entry.path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.'))
     }
 
     /// Displays a symbolic link file name within the given writer.
@@ -56,13 +318,17 @@ impl<'ar> NameDisplay<'ar> {
             cwrite!(s, bright_red; f, "{v}")
         }
 
-        if name.starts_with('.') {
-            cwrite!(self, cyan; f, "{name}")?;
-        } else {
-            cwrite!(self, bright_cyan; f, "{name}")?;
+        // Dangling links are highlighted even when they aren't being resolved, since they usually indicate a problem.
+        let is_broken = !self.arguments.plain_broken_symlinks && entry.path.try_exists().is_ok_and(|exists| !exists);
+
+        match (is_broken, Self::is_hidden(entry)) {
+            (true, true) => cwrite!(self, red; f, "{name}")?,
+            (true, false) => cwrite!(self, bright_red; f, "{name}")?,
+            (false, true) => cwrite!(self, cyan; f, "{name}")?,
+            (false, false) => cwrite!(self, bright_cyan; f, "{name}")?,
         }
 
-        if !self.arguments.show_symlinks {
+        if !self.arguments.show_symlinks || self.arguments.compact_symlinks {
             return Ok(());
         }
 
@@ -76,7 +342,9 @@ impl<'ar> NameDisplay<'ar> {
             return fail(self, f, path.to_string_lossy());
         }
 
-        let Ok(data) = std::fs::metadata(&resolve_path) else {
+        // The target's own metadata is used so that a target that is itself a link is displayed as one, resolving the
+        // chain one link at a time. Cycles never reach this point, as their paths fail to resolve above.
+        let Ok(data) = std::fs::symlink_metadata(&resolve_path) else {
             return fail(self, f, path.to_string_lossy());
         };
 
@@ -92,25 +360,20 @@ impl<'ar> NameDisplay<'ar> {
 
     /// Displays a directory name within the given writer.
     ///
+    /// Any trailing separators are removed, such as from the resolved target of a symbolic link written as `dir//`, so
+    /// that at most the single separator of the directory's indicator follows the name.
+    ///
     /// # Errors
     ///
     /// This function will return an error if the entry fails to display.
-    fn show_dir<W: Write>(&self, f: &mut W, name: &str) -> Result<()> {
-        if name.starts_with('.') {
-            cwrite!(self, blue; f, "{name}")?;
+    fn show_dir<W: Write>(&self, f: &mut W, entry: &Entry, name: &str) -> Result<()> {
+        let name = name.trim_end_matches(MAIN_SEPARATOR);
 
-            if !name.ends_with(MAIN_SEPARATOR) {
-                cwrite!(self, blue; f, "{MAIN_SEPARATOR}")?;
-            }
+        if Self::is_hidden(entry) {
+            cwrite!(self, blue; f, "{name}")
         } else {
-            cwrite!(self, bright_blue; f, "{name}")?;
-
-            if !name.ends_with(MAIN_SEPARATOR) {
-                cwrite!(self, bright_blue; f, "{MAIN_SEPARATOR}")?;
-            }
+            cwrite!(self, bright_blue; f, "{name}")
         }
-
-        Ok(())
     }
 
     /// Displays a directory name within the given writer.
@@ -121,16 +384,22 @@ impl<'ar> NameDisplay<'ar> {
     fn show_file<W: Write>(&self, f: &mut W, entry: &Entry, name: &str) -> Result<()> {
         if entry.path.is_executable() {
             if entry.path.file_stem().is_some_and(|p| p.to_string_lossy().starts_with('.')) {
-                cwrite!(self, green; f, "{name}")?;
+                cwrite!(self, green; f, "{name}")
             } else {
-                cwrite!(self, bright_green; f, "{name}")?;
+                cwrite!(self, bright_green; f, "{name}")
             }
-
-            cwrite!(self, white; f, "*")
         } else if entry.path.file_stem().is_some_and(|p| p.to_string_lossy().starts_with('.')) {
             cwrite!(self, bright_black; f, "{name}")
         } else {
-            cwrite!(self, white; f, "{name}")
+            match Self::file_category(entry) {
+                Some(FileCategory::Image) => cwrite!(self, bright_magenta; f, "{name}"),
+                Some(FileCategory::Video) => cwrite!(self, magenta; f, "{name}"),
+                Some(FileCategory::Audio) => cwrite!(self, cyan; f, "{name}"),
+                Some(FileCategory::Archive) => cwrite!(self, red; f, "{name}"),
+                Some(FileCategory::Source) => cwrite!(self, yellow; f, "{name}"),
+                Some(FileCategory::Document) => cwrite!(self, bright_white; f, "{name}"),
+                None => cwrite!(self, white; f, "{name}"),
+            }
         }
     }
 }
@@ -143,20 +412,101 @@ impl HasColor for NameDisplay<'_> {
 
 impl Displayer for NameDisplay<'_> {
     fn show<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
-        let name = if self.trim_file_paths {
-            let os_name = entry.path.file_name().unwrap_or(entry.path.as_os_str());
-
-            os_name.to_string_lossy().into_owned()
+        let name = self.relative_name(entry).unwrap_or_else(|| self.path_name(entry));
+        let name = match self.arguments.truncate_names {
+            Some(width) => self.truncate(&name, width),
+            None => name,
+        };
+        // Quoting already escapes control characters, so escaping is only needed for unquoted names.
+        let name = if self.arguments.quote_names {
+            Self::quote(&name)
+        } else if self.arguments.escape_names {
+            Self::escape(&name)
         } else {
-            entry.path.to_string_lossy().into_owned()
+            name
         };
 
         if entry.data.is_symlink() {
-            self.show_symlink(f, entry, &name)
+            self.show_symlink(f, entry, &name)?;
         } else if entry.data.is_dir() {
-            self.show_dir(f, &name)
+            self.show_dir(f, entry, &name)?;
         } else {
-            self.show_file(f, entry, &name)
+            self.show_file(f, entry, &name)?;
+        }
+
+        self.show_indicator(f, entry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{PathBuf, MAIN_SEPARATOR_STR};
+
+    use super::NameDisplay;
+    use crate::arguments::Arguments;
+    use crate::display::Displayer;
+    use crate::Entry;
+
+    #[test]
+    fn quote_matches_escape() {
+        assert_eq!(NameDisplay::escape("a\tb\x1B\\"), "a\\tb\\033\\\\");
+        assert_eq!(NameDisplay::quote("a\tb\x1B\\"), "\"a\\tb\\033\\\\\"");
+        assert_eq!(NameDisplay::quote("say \"hi\"\x07"), "\"say \\\"hi\\\"\\a\"");
+    }
+
+    #[test]
+    fn directories_have_one_separator() -> std::io::Result<()> {
+        let directory = std::env::temp_dir();
+        let entry = Entry::new(directory.clone(), directory.metadata()?);
+        let arguments = Arguments { color: Some(false), full_path: true, ..Arguments::default() };
+        let display = NameDisplay::new(&arguments);
+        let name = directory.to_string_lossy().trim_end_matches(MAIN_SEPARATOR_STR).to_owned();
+
+        for separators in [0, 1, 3] {
+            let path = format!("{name}{}", MAIN_SEPARATOR_STR.repeat(separators));
+            let mut output = Vec::new();
+
+            display.show_dir(&mut output, &entry, &path)?;
+
+            assert_eq!(String::from_utf8_lossy(&output), name, "with {separators} separators");
+
+            let mut output = Vec::new();
+
+            display.show(&mut output, &Entry { path: PathBuf::from(&path), ..entry.clone() })?;
+
+            assert_eq!(String::from_utf8_lossy(&output), format!("{name}{MAIN_SEPARATOR_STR}"));
         }
+
+        Ok(())
+    }
+
+    #[cfg(target_family = "unix")]
+    #[test]
+    fn symlinked_directories_have_one_separator() -> std::io::Result<()> {
+        let root = std::env::temp_dir().join(format!("rs-name-{}", std::process::id()));
+        let arguments = Arguments { color: Some(false), show_symlinks: true, ..Arguments::default() };
+        let display = NameDisplay::new(&arguments);
+
+        std::fs::create_dir_all(root.join("dir"))?;
+        std::os::unix::fs::symlink("dir", root.join("link"))?;
+        std::os::unix::fs::symlink("dir//", root.join("slashed"))?;
+
+        let [link, slashed] = ["link", "slashed"].map(|name| -> std::io::Result<String> {
+            let path = root.join(name);
+            let mut output = Vec::new();
+
+            display.show(&mut output, &Entry::new(path.clone(), path.symlink_metadata()?))?;
+
+            Ok(String::from_utf8_lossy(&output).into_owned())
+        });
+
+        std::fs::remove_dir_all(&root)?;
+
+        let target = root.join("dir");
+
+        assert_eq!(link?, format!("link -> {}{MAIN_SEPARATOR_STR}", target.to_string_lossy()));
+        assert_eq!(slashed?, format!("slashed -> {}{MAIN_SEPARATOR_STR}", target.to_string_lossy()));
+
+        Ok(())
     }
 }
@@ -15,12 +15,26 @@
 // You should have received a copy of the GNU Affero General Public License along with rs. If not,
 // see <https://www.gnu.org/licenses/>.
 
+use core::cell::RefCell;
+use std::collections::HashMap;
 use std::io::{Result, Write};
 
 use crate::{arguments::Arguments, cwrite, Entry};
 
 use super::{Displayer, HasColor};
 
+/// The key used to cache resolved owner names.
+#[cfg(target_family = "unix")]
+type OwnerKey = u32;
+/// The key used to cache resolved owner names.
+#[cfg(target_family = "windows")]
+type OwnerKey = Box<str>;
+
+thread_local! {
+    /// A cache of previously resolved owner names, shared between displaying and sorting.
+    static OWNER_NAMES: RefCell<HashMap<OwnerKey, Box<str>>> = RefCell::default();
+}
+
 /// Displays an entry's file owner.
 #[non_exhaustive]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -36,21 +50,89 @@ impl<'ar> OwnerDisplay<'ar> {
         Self { arguments }
     }
 
+    /// Returns the displayed owner of the given entry.
+    pub(super) fn get_owner(&self, entry: &Entry) -> Box<str> {
+        let owner = if self.arguments.numeric_owner { self.get_owner_id(entry) } else { Self::get_owner_name(entry) };
+
+        // A single failed lookup should not abort the entire listing.
+        owner.unwrap_or_else(|_| self.get_fallback_name(entry))
+    }
+
+    /// Returns the cached name for the given key, resolving and caching it if it is not yet present.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the name could not be resolved.
+    fn get_cached(key: OwnerKey, resolve: impl FnOnce() -> Result<Box<str>>) -> Result<Box<str>> {
+        if let Some(name) = OWNER_NAMES.with_borrow(|names| names.get(&key).cloned()) {
+            return Ok(name);
+        }
+
+        let name = resolve()?;
+
+        OWNER_NAMES.with_borrow_mut(|names| names.insert(key, name.clone()));
+
+        Ok(name)
+    }
+
+    /// Returns the numeric identifier of the given entry's owner.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the identifier could not be resolved.
+    #[cfg(target_family = "unix")]
+    #[expect(clippy::unnecessary_wraps, reason = "this mirrors the signature of the Windows implementation")]
+    #[expect(clippy::unused_self, reason = "this mirrors the signature of the Windows implementation")]
+    fn get_owner_id(&self, entry: &Entry) -> Result<Box<str>> {
+        use std::os::unix::fs::MetadataExt;
+
+        Ok(itoa::Buffer::new().format(entry.data.uid()).into())
+    }
+
+    /// Returns the numeric identifier of the given entry's owner.
+    ///
+    /// Windows does not have numeric user identifiers, so this falls back to resolving the owner's name.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the identifier could not be resolved.
+    #[cfg(target_family = "windows")]
+    #[expect(clippy::unused_self, reason = "this mirrors the signature of the Unix implementation")]
+    fn get_owner_id(&self, entry: &Entry) -> Result<Box<str>> {
+        Self::get_owner_name(entry)
+    }
+
+    /// Returns the name to display if the given entry's owner could not be resolved.
+    #[cfg(target_family = "unix")]
+    fn get_fallback_name(&self, entry: &Entry) -> Box<str> {
+        self.get_owner_id(entry).unwrap_or_else(|_| "unknown".into())
+    }
+
+    /// Returns the name to display if the given entry's owner could not be resolved.
+    #[cfg(target_family = "windows")]
+    #[expect(clippy::unused_self, reason = "this mirrors the signature of the Unix implementation")]
+    fn get_fallback_name(&self, _: &Entry) -> Box<str> {
+        "unknown".into()
+    }
+
     /// Returns the name of the given entry's owner.
     ///
     /// # Errors
     ///
     /// This function will return an error if the name could not be resolved.
     #[cfg(target_family = "unix")]
-    fn get_owner_name(entry: &Entry) -> Result<Box<str>> {
+    pub(crate) fn get_owner_name(entry: &Entry) -> Result<Box<str>> {
         use std::os::unix::fs::MetadataExt;
 
         use nix::unistd::{Uid, User};
 
         let uid = entry.data.uid();
-        let user = User::from_uid(Uid::from_raw(uid))?;
 
-        Ok(user.map_or_else(|| "unknown".into(), |v| v.name.into_boxed_str()))
+        Self::get_cached(uid, || {
+            let user = User::from_uid(Uid::from_raw(uid))?;
+
+            Ok(user.map_or_else(|| "unknown".into(), |v| v.name.into_boxed_str()))
+        })
     }
 
     /// Returns the name of the given entry's owner.
@@ -59,21 +141,28 @@ impl<'ar> OwnerDisplay<'ar> {
     ///
     /// This function will return an error if the name could not be resolved.
     #[cfg(target_family = "windows")]
-    fn get_owner_name(entry: &Entry) -> Result<Box<str>> {
+    pub(crate) fn get_owner_name(entry: &Entry) -> Result<Box<str>> {
+        use std::io::{Error, ErrorKind};
+
         use windows_permissions::{
             constants::{SeObjectType, SecurityInformation},
-            wrappers::{GetSecurityInfo, LookupAccountSid},
+            wrappers::{ConvertSidToStringSid, GetNamedSecurityInfo, LookupAccountSid},
         };
 
-        if entry.data.is_dir() {
-            return Ok("-".into());
-        }
+        // Querying by path avoids opening a handle, which can fail for locked or in-use files.
+        let descriptor =
+            GetNamedSecurityInfo(entry.path.as_os_str(), SeObjectType::SE_FILE_OBJECT, SecurityInformation::Owner)?;
+        let Some(sid) = descriptor.owner() else {
+            return Err(Error::new(ErrorKind::NotFound, "missing owner security identifier"));
+        };
+
+        let key = ConvertSidToStringSid(sid)?.to_string_lossy().into();
 
-        let file = std::fs::File::open(&entry.path)?;
-        let descriptor = GetSecurityInfo(&file, SeObjectType::SE_FILE_OBJECT, SecurityInformation::Owner)?;
-        let (name, _) = LookupAccountSid(descriptor.owner().expect("missing required data"))?;
+        Self::get_cached(key, || {
+            let (name, _) = LookupAccountSid(sid)?;
 
-        Ok(name.to_string_lossy().into())
+            Ok(name.to_string_lossy().into())
+        })
     }
 }
 
@@ -85,6 +174,6 @@ impl HasColor for OwnerDisplay<'_> {
 
 impl Displayer for OwnerDisplay<'_> {
     fn show<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
-        cwrite!(self, bright_green; f, "{:>8}", Self::get_owner_name(entry)?)
+        cwrite!(self, bright_green; f, "{:>8}", self.get_owner(entry))
     }
 }
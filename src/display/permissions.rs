@@ -24,6 +24,31 @@ use super::{Displayer, HasColor};
 use crate::arguments::Arguments;
 use crate::{cwrite, Entry};
 
+/// The bits of a Unix file mode that determine the file's type.
+#[cfg(target_family = "unix")]
+pub(super) const FILE_TYPE_MASK: u32 = 0o0_170_000;
+/// The file type of a socket.
+#[cfg(target_family = "unix")]
+const SOCKET: u32 = 0o0_140_000;
+/// The file type of a symbolic link.
+#[cfg(target_family = "unix")]
+const SYMBOLIC_LINK: u32 = 0o0_120_000;
+/// The file type of a regular file.
+#[cfg(target_family = "unix")]
+const FILE: u32 = 0o0_100_000;
+/// The file type of a block device.
+#[cfg(target_family = "unix")]
+pub(super) const BLOCK_DEVICE: u32 = 0o0_060_000;
+/// The file type of a directory.
+#[cfg(target_family = "unix")]
+const DIRECTORY: u32 = 0o0_040_000;
+/// The file type of a character device.
+#[cfg(target_family = "unix")]
+pub(super) const CHARACTER_DEVICE: u32 = 0o0_020_000;
+/// The file type of a named pipe.
+#[cfg(target_family = "unix")]
+const FIFO_PIPE: u32 = 0o0_010_000;
+
 /// Displays an entry's permissions.
 #[non_exhaustive]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -45,16 +70,7 @@ impl<'ar> PermissionsDisplay<'ar> {
     ///
     /// This function will return an error if the permissions could not be displayed.
     #[cfg(target_family = "unix")]
-    fn show_entry<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
-        const FILE_TYPE_MASK: u32 = 0o0_170_000;
-        const SOCKET: u32 = 0o0_140_000;
-        const SYMBOLIC_LINK: u32 = 0o0_120_000;
-        const FILE: u32 = 0o0_100_000;
-        const BLOCK_DEVICE: u32 = 0o0_060_000;
-        const DIRECTORY: u32 = 0o0_040_000;
-        const CHARACTER_DEVICE: u32 = 0o0_020_000;
-        const FIFO_PIPE: u32 = 0o0_010_000;
-
+    pub(super) fn show_entry<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
         let mode = <Metadata as std::os::unix::fs::MetadataExt>::mode(&entry.data);
         let string = ::umask::Mode::from(mode).to_string();
 
@@ -71,20 +87,49 @@ impl<'ar> PermissionsDisplay<'ar> {
 
         self.show_char(f, character)?;
 
-        for character in string.chars() {
-            self.show_char(f, character)?;
+        for (index, character) in string.chars().enumerate() {
+            self.show_special_char(f, index, character)?;
         }
 
         Ok(())
     }
 
+    /// Displays an entry's Unix permission character, highlighting the set-user-ID, set-group-ID, and sticky bits.
+    ///
+    /// The `umask` crate renders these bits in place of the execute permission of their respective group, using a
+    /// lowercase character if the execute permission is also set, and an uppercase character otherwise.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the permission could not be displayed.
+    #[cfg(target_family = "unix")]
+    fn show_special_char<W: Write>(&self, f: &mut W, index: usize, character: char) -> Result<()> {
+        /// The index of the user's execute permission.
+        const SETUID_INDEX: usize = 2;
+        /// The index of the group's execute permission.
+        const SETGID_INDEX: usize = 5;
+        /// The index of everyone else's execute permission.
+        const STICKY_INDEX: usize = 8;
+
+        match (index, character) {
+            // Set-user-ID.
+            (SETUID_INDEX, c @ ('s' | 'S')) => cwrite!(self, on_red; f, "{c}"),
+            // Set-group-ID.
+            (SETGID_INDEX, c @ ('s' | 'S')) => cwrite!(self, on_purple; f, "{c}"),
+            // Sticky.
+            (STICKY_INDEX, c @ ('t' | 'T')) => cwrite!(self, on_blue; f, "{c}"),
+            // Anything else.
+            (_, c) => self.show_char(f, c),
+        }
+    }
+
     /// Displays an entry's Windows permissions.
     ///
     /// # Errors
     ///
     /// This function will return an error if the permissions could not be displayed.
     #[cfg(target_family = "windows")]
-    fn show_entry<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
+    pub(super) fn show_entry<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
         let bits = <Metadata as std::os::windows::fs::MetadataExt>::file_attributes(&entry.data);
         let string = WindowsPermissions { bits }.to_string();
 
@@ -23,7 +23,27 @@ use std::io::{Result, Write};
 use super::{Displayer, HasColor};
 use crate::{arguments::Arguments, cwrite, Entry};
 
-/// Displays an entry's name.
+/// The colors that sizes may be displayed in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SizeColor {
+    /// A regular size.
+    Normal,
+    /// A placeholder for entries without a meaningful size.
+    Dim,
+    /// The size of a sparse file.
+    Sparse,
+}
+
+/// A size formatted for display.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum FormattedSize {
+    /// A value scaled to a unit, alongside that unit's suffix.
+    Scaled(String, &'static str),
+    /// An exact byte count.
+    Bytes(String),
+}
+
+/// Displays an entry's size.
 #[non_exhaustive]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SizeDisplay<'ar> {
@@ -33,7 +53,19 @@ pub struct SizeDisplay<'ar> {
 
 impl<'ar> SizeDisplay<'ar> {
     /// All accepted human-readable byte suffixes.
-    pub const SUFFIXES: [&'static str; 7] = ["B  ", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+    pub const SUFFIXES: [&'static str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+    /// All accepted human-readable byte suffixes when using SI units.
+    pub const SI_SUFFIXES: [&'static str; 7] = ["B", "kB", "MB", "GB", "TB", "PB", "EB"];
+    /// The width of the displayed size column, beyond which raw values are truncated.
+    pub const WIDTH: usize = 9;
+    /// The width of the displayed size column when digits are grouped, leaving room for each separator.
+    const GROUPED_WIDTH: usize = Self::WIDTH + ((Self::WIDTH - 1) / 3);
+    /// The width of the human-readable suffix sub-field.
+    const SUFFIX_WIDTH: usize = 3;
+    /// The width of the human-readable value sub-field, leaving room for the suffix and a separating space.
+    const VALUE_WIDTH: usize = Self::WIDTH - Self::SUFFIX_WIDTH - 1;
+    /// The marker appended to truncated values.
+    const TRUNCATION_MARKER: &'static str = "...";
 
     /// Creates a new [`SizeDisplay`].
     #[must_use]
@@ -41,64 +73,222 @@ impl<'ar> SizeDisplay<'ar> {
         Self { arguments }
     }
 
-    /// Displays the given value, aligned to the right and capped at 9 characters.
+    /// Returns the number of decimal places displayed in scaled sizes.
+    fn precision(&self) -> usize {
+        self.arguments.size_precision.unwrap_or(1)
+    }
+
+    /// Returns the width of the human-readable value sub-field, which grows with each decimal place beyond the first.
+    fn value_width(&self) -> usize {
+        Self::VALUE_WIDTH + self.precision().saturating_sub(1)
+    }
+
+    /// Returns whether sizes are scaled to units rather than displayed as byte counts.
+    const fn is_scaled(&self) -> bool {
+        self.arguments.human_readable && !self.arguments.raw_sizes && self.arguments.block_size.is_none()
+    }
+
+    /// Formats the given size in bytes, using the unit system selected by the program's arguments.
+    ///
+    /// Every displayed size is formatted through this function, so that the unit system is applied uniformly.
+    fn format(&self, bytes: u64) -> FormattedSize {
+        if self.is_scaled() {
+            if let Some((value, suffix)) = self.scale(bytes) {
+                return FormattedSize::Scaled(value, suffix);
+            }
+        }
+
+        // Partially filled blocks are counted as whole blocks, so that non-empty files never display a size of zero.
+        let count = match self.arguments.block_size {
+            Some(block_size) if !self.arguments.raw_sizes => bytes.div_ceil(block_size),
+            _ => bytes,
+        };
+        let string = itoa::Buffer::new().format(count).to_owned();
+
+        FormattedSize::Bytes(if self.arguments.group_thousands { Self::group_thousands(&string) } else { string })
+    }
+
+    /// Displays the given value, aligned to the right and capped at [`WIDTH`](Self::WIDTH) characters.
+    ///
+    /// Grouped digits are aligned to a wider column, and are never truncated, as a partial number would be misleading.
     ///
     /// # Errors
     ///
     /// This function will return an error if the value cannot be displayed.
-    fn show_aligned<W, T>(&self, f: &mut W, v: T, dim: bool) -> Result<()>
+    fn show_aligned<W, T>(&self, f: &mut W, v: T, color: SizeColor) -> Result<()>
     where
         W: Write,
         T: Display,
     {
-        let output = if self.arguments.human_readable {
-            v.to_string()
-        } else {
-            let string = v.to_string();
+        let string = v.to_string();
 
-            if string.len() <= 9 {
-                string
-            } else {
-                format!("{:>6}...", &string[..6])
-            }
+        if self.arguments.group_thousands {
+            return self.show_colored(f, format_args!("{string:>width$}", width = Self::GROUPED_WIDTH), color);
+        }
+
+        let output = if string.len() <= Self::WIDTH {
+            string
+        } else {
+            format!("{}{}", &string[..Self::WIDTH - Self::TRUNCATION_MARKER.len()], Self::TRUNCATION_MARKER)
         };
 
-        if dim {
-            cwrite!(self, bright_black; f, "{output:>9}")
+        self.show_colored(f, format_args!("{output:>width$}", width = Self::WIDTH), color)
+    }
+
+    /// Displays the given output in the given color.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the output cannot be displayed.
+    fn show_colored<W: Write>(&self, f: &mut W, output: impl Display, color: SizeColor) -> Result<()> {
+        match color {
+            SizeColor::Normal => cwrite!(self, bright_green; f, "{output}"),
+            SizeColor::Dim => cwrite!(self, bright_black; f, "{output}"),
+            SizeColor::Sparse => cwrite!(self, bright_yellow; f, "{output}"),
+        }
+    }
+
+    /// Displays a placeholder in place of a size, for entries without a meaningful size.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the placeholder cannot be displayed.
+    fn show_placeholder<W: Write>(&self, f: &mut W) -> Result<()> {
+        if self.is_scaled() {
+            self.show_with_suffix(f, "-", "-", SizeColor::Dim)
         } else {
-            cwrite!(self, bright_green; f, "{output:>9}")
+            self.show_aligned(f, "-", SizeColor::Dim)
+        }
+    }
+
+    /// Returns the given string with its digits separated into groups of three by commas.
+    ///
+    /// Strings that contain anything other than digits are returned unchanged.
+    fn group_thousands(string: &str) -> String {
+        if !string.bytes().all(|byte| byte.is_ascii_digit()) {
+            return string.to_owned();
         }
+
+        let mut output = String::with_capacity(string.len() + (string.len() / 3));
+
+        for (index, character) in string.chars().enumerate() {
+            if index > 0 && (string.len() - index).is_multiple_of(3) {
+                output.push(',');
+            }
+
+            output.push(character);
+        }
+
+        output
     }
 
-    /// Displays the given size in bytes in a human-readable format.
+    /// Displays the given value and unit suffix as separate sub-fields, so that values align to the right while suffixes
+    /// align to the left.
     ///
     /// # Errors
     ///
     /// This function will return an error if the value cannot be displayed.
+    fn show_with_suffix<W, T>(&self, f: &mut W, v: T, suffix: &str, color: SizeColor) -> Result<()>
+    where
+        W: Write,
+        T: Display,
+    {
+        let output = format!(
+            "{v:>value_width$} {suffix:<suffix_width$}",
+            value_width = self.value_width(),
+            suffix_width = Self::SUFFIX_WIDTH
+        );
+
+        self.show_colored(f, output, color)
+    }
+
+    /// Returns the given size in bytes scaled to its largest fitting unit, alongside that unit's suffix.
+    ///
+    /// Units are powers of 1024, or powers of 1000 if SI units are enabled.
     #[expect(clippy::cast_precision_loss, reason = "ints never be high enough to lose meaningful precision")]
-    fn show_human_readable<W: Write>(&self, f: &mut W, bytes: u64) -> Result<()> {
-        if bytes == 0 {
-            return self.show_aligned(f, format_args!("0 {}", Self::SUFFIXES[0]), false);
-        }
+    fn scale(&self, bytes: u64) -> Option<(String, &'static str)> {
+        let (base, suffixes) =
+            if self.arguments.si_units { (1000, &Self::SI_SUFFIXES) } else { (1024, &Self::SUFFIXES) };
+        let mut unit: u64 = 1;
 
-        for (index, suffix) in Self::SUFFIXES.iter().enumerate() {
-            let min_bound = 1 << (10 * index);
-            let max_bound = 1 << (10 * (index + 1));
-            let suffix_bounds = min_bound..max_bound;
+        for (index, suffix) in suffixes.iter().enumerate() {
+            let next_unit = unit.checked_mul(base);
 
-            if suffix_bounds.contains(&bytes) {
-                return if index == 0 {
-                    self.show_aligned(f, format_args!("{} {suffix}", itoa::Buffer::new().format(bytes)), false)
+            if next_unit.is_none_or(|next_unit| bytes < next_unit) {
+                return Some(if index == 0 {
+                    (itoa::Buffer::new().format(bytes).to_owned(), suffix)
                 } else {
-                    let value = bytes as f64 / min_bound as f64;
-                    let value = (value * 10.0).round() / 10.0;
+                    let value = bytes as f64 / unit as f64;
+
+                    (format!("{value:.precision$}", precision = self.precision()), suffix)
+                });
+            }
+
+            unit = next_unit?;
+        }
+
+        None
+    }
+
+    /// Displays the given size in bytes within the size column.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the value cannot be displayed.
+    fn show_bytes<W: Write>(&self, f: &mut W, bytes: u64, color: SizeColor) -> Result<()> {
+        match self.format(bytes) {
+            FormattedSize::Scaled(value, suffix) => self.show_with_suffix(f, value, suffix, color),
+            FormattedSize::Bytes(value) => self.show_aligned(f, value, color),
+        }
+    }
 
-                    self.show_aligned(f, format_args!("{} {suffix}", ryu::Buffer::new().format_finite(value)), false)
-                };
+    /// Displays the given total size in bytes without aligning it to the size column.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the value cannot be displayed.
+    pub fn show_total<W: Write>(&self, f: &mut W, bytes: u64) -> Result<()> {
+        match self.format(bytes) {
+            FormattedSize::Scaled(value, suffix) => {
+                self.show_colored(f, format_args!("{value} {suffix}"), SizeColor::Normal)
             }
+            FormattedSize::Bytes(value) => self.show_colored(f, value, SizeColor::Normal),
+        }
+    }
+
+    /// Returns whether the given entry is a sparse file, with less than half of its apparent size allocated on disk.
+    #[cfg(target_family = "unix")]
+    fn is_sparse(entry: &Entry) -> bool {
+        use std::os::unix::fs::MetadataExt;
+
+        /// The size of the blocks reported by [`MetadataExt::blocks`], which is independent of the file system.
+        const BLOCK_SIZE: u64 = 512;
+
+        entry.data.is_file() && entry.data.blocks().saturating_mul(BLOCK_SIZE) < entry.data.len() / 2
+    }
+
+    /// Returns whether the given entry is a sparse file.
+    ///
+    /// Allocated sizes are only available on Unix, so this always returns `false` elsewhere.
+    #[cfg(not(target_family = "unix"))]
+    const fn is_sparse(_: &Entry) -> bool {
+        false
+    }
+
+    /// Returns the major and minor device numbers of the given entry, if it is a block or character device.
+    #[cfg(target_family = "unix")]
+    fn device_numbers(entry: &Entry) -> Option<(impl Display, impl Display)> {
+        use std::os::unix::fs::MetadataExt;
+
+        use super::permissions::{BLOCK_DEVICE, CHARACTER_DEVICE, FILE_TYPE_MASK};
+
+        if !matches!(entry.data.mode() & FILE_TYPE_MASK, BLOCK_DEVICE | CHARACTER_DEVICE) {
+            return None;
         }
 
-        self.show_aligned(f, bytes, false)
+        let device = libc::dev_t::try_from(entry.data.rdev()).ok()?;
+
+        Some((libc::major(device), libc::minor(device)))
     }
 }
 
@@ -110,16 +300,62 @@ impl HasColor for SizeDisplay<'_> {
 
 impl Displayer for SizeDisplay<'_> {
     fn show<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
+        // A link's own size is only the length of its target's path, so the size of the target itself is displayed.
+        if self.arguments.symlink_target_size && entry.data.is_symlink() {
+            return match std::fs::metadata(&entry.path) {
+                Ok(data) => self.show(f, &Entry::new(entry.path.clone(), data)),
+                Err(_) => self.show_placeholder(f),
+            };
+        }
+
         if entry.data.is_dir() {
-            return self.show_aligned(f, if self.arguments.human_readable { "- -  " } else { "-" }, true);
+            return self.show_placeholder(f);
         }
 
-        let bytes = entry.data.len();
+        // Devices have no meaningful size, so their device numbers are displayed instead.
+        #[cfg(target_family = "unix")]
+        if let Some((major, minor)) = Self::device_numbers(entry) {
+            return self.show_aligned(f, format_args!("{major}, {minor}"), SizeColor::Normal);
+        }
 
-        if self.arguments.human_readable {
-            self.show_human_readable(f, bytes)
+        let bytes = entry.data.len();
+        let color = if self.arguments.mark_sparse && Self::is_sparse(entry) {
+            SizeColor::Sparse
         } else {
-            self.show_aligned(f, bytes, false)
-        }
+            SizeColor::Normal
+        };
+
+        self.show_bytes(f, bytes, color)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SizeColor, SizeDisplay};
+    use crate::arguments::Arguments;
+
+    /// Displays the given size in bytes within the size column.
+    fn show(arguments: &Arguments, bytes: u64) -> std::io::Result<String> {
+        let mut output = Vec::new();
+
+        SizeDisplay::new(arguments).show_bytes(&mut output, bytes, SizeColor::Normal)?;
+
+        String::from_utf8(output).map_err(std::io::Error::other)
+    }
+
+    #[test]
+    fn grouped_sizes_are_not_truncated() -> std::io::Result<()> {
+        let arguments = Arguments { color: Some(false), ..Arguments::default() };
+
+        assert_eq!(show(&arguments, 12_345_678)?, " 12345678");
+        assert_eq!(show(&arguments, 12_345_678_901)?, "123456...");
+
+        let arguments = Arguments { group_thousands: true, ..arguments };
+
+        assert_eq!(show(&arguments, 1_234)?, "      1,234");
+        assert_eq!(show(&arguments, 12_345_678)?, " 12,345,678");
+        assert_eq!(show(&arguments, 12_345_678_901)?, "12,345,678,901");
+
+        Ok(())
     }
 }
@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+use core::ops::AddAssign;
+use std::io::{Result, Write};
+use std::time::SystemTime;
+
+use time::{OffsetDateTime, Time, UtcOffset};
+
+use super::HasColor;
+use crate::{arguments::Arguments, cwrite, Entry};
+
+/// A tally of the kinds of entries within a listing.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    /// The number of listed files.
+    pub files: usize,
+    /// The number of listed directories.
+    pub directories: usize,
+    /// The number of listed symbolic links.
+    pub symlinks: usize,
+    /// The combined size of the listed files in bytes.
+    pub bytes: u64,
+    /// The number of entries that were hidden by filters, such as `--type` or `--ignore-backups`.
+    pub filtered: usize,
+    /// The number of listed entries modified today.
+    pub modified_today: usize,
+    /// The number of listed entries modified this week, excluding today.
+    pub modified_this_week: usize,
+    /// The number of listed entries modified before this week.
+    pub modified_older: usize,
+}
+
+impl Summary {
+    /// Adds the given entry to the tally.
+    pub fn add(&mut self, entry: &Entry) {
+        if entry.data.is_symlink() {
+            self.symlinks += 1;
+        } else if entry.data.is_dir() {
+            self.directories += 1;
+        } else {
+            self.files += 1;
+            self.bytes += entry.data.len();
+        }
+    }
+
+    /// Adds the given entry to the tally of modification ages, bucketed by the given cutoffs.
+    ///
+    /// Entries without a modification date are not counted.
+    pub fn add_age(&mut self, entry: &Entry, cutoffs: &AgeCutoffs) {
+        let Ok(modified) = entry.data.modified() else { return };
+
+        if modified >= cutoffs.today {
+            self.modified_today += 1;
+        } else if modified >= cutoffs.this_week {
+            self.modified_this_week += 1;
+        } else {
+            self.modified_older += 1;
+        }
+    }
+}
+
+/// The times that separate a summary's modification age buckets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AgeCutoffs {
+    /// The start of the current day.
+    today: SystemTime,
+    /// The start of the current week, which begins on Monday.
+    this_week: SystemTime,
+}
+
+impl AgeCutoffs {
+    /// Returns the cutoffs for the current time, in the local offset if it can be determined and in UTC otherwise.
+    #[must_use]
+    pub fn now() -> Self {
+        let offset = UtcOffset::current_local_offset().unwrap_or(UtcOffset::UTC);
+
+        Self::at(OffsetDateTime::now_utc().to_offset(offset))
+    }
+
+    /// Returns the cutoffs for the given time, whose offset determines when its day and week begin.
+    #[must_use]
+    pub fn at(now: OffsetDateTime) -> Self {
+        let today = now.replace_time(Time::MIDNIGHT);
+        let this_week = today - time::Duration::days(i64::from(now.weekday().number_days_from_monday()));
+
+        Self { today: today.into(), this_week: this_week.into() }
+    }
+}
+
+impl AddAssign for Summary {
+    fn add_assign(&mut self, rhs: Self) {
+        self.files += rhs.files;
+        self.directories += rhs.directories;
+        self.symlinks += rhs.symlinks;
+        self.bytes += rhs.bytes;
+        self.filtered += rhs.filtered;
+        self.modified_today += rhs.modified_today;
+        self.modified_this_week += rhs.modified_this_week;
+        self.modified_older += rhs.modified_older;
+    }
+}
+
+/// Displays a listing's summary.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SummaryDisplay<'ar> {
+    /// The program's arguments.
+    arguments: &'ar Arguments,
+}
+
+impl<'ar> SummaryDisplay<'ar> {
+    /// Creates a new [`SummaryDisplay`].
+    #[must_use]
+    pub const fn new(arguments: &'ar Arguments) -> Self {
+        Self { arguments }
+    }
+
+    /// Displays the given summary.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the summary could not be displayed.
+    pub fn show<W: Write>(&self, f: &mut W, summary: &Summary) -> Result<()> {
+        #[inline]
+        const fn plural(count: usize, singular: &'static str, plural: &'static str) -> &'static str {
+            if count == 1 {
+                singular
+            } else {
+                plural
+            }
+        }
+
+        cwrite!(self, bold; f, "{}", summary.files)?;
+        cwrite!(self, white; f, " {}", plural(summary.files, "file", "files"))?;
+        cwrite!(self, bright_black; f, ", ")?;
+        cwrite!(self, bold; f, "{}", summary.directories)?;
+        cwrite!(self, bright_blue; f, " {}", plural(summary.directories, "directory", "directories"))?;
+        cwrite!(self, bright_black; f, ", ")?;
+        cwrite!(self, bold; f, "{}", summary.symlinks)?;
+        cwrite!(self, bright_cyan; f, " {}", plural(summary.symlinks, "symlink", "symlinks"))?;
+
+        if summary.filtered > 0 {
+            let shown = summary.files + summary.directories + summary.symlinks;
+
+            cwrite!(self, bright_black; f, ", ")?;
+            cwrite!(self, white; f, "showing ")?;
+            cwrite!(self, bold; f, "{shown}")?;
+            cwrite!(self, white; f, " of ")?;
+            cwrite!(self, bold; f, "{}", shown + summary.filtered)?;
+            cwrite!(self, white; f, " {}", plural(shown + summary.filtered, "entry", "entries"))?;
+            cwrite!(self, bright_black; f, " ({} filtered)", summary.filtered)?;
+        }
+
+        if self.arguments.summary_ages {
+            self.show_ages(f, summary)?;
+        }
+
+        Ok(())
+    }
+
+    /// Displays the given summary's modification ages on a separate line.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the ages could not be displayed.
+    fn show_ages<W: Write>(&self, f: &mut W, summary: &Summary) -> Result<()> {
+        f.write_all(b"\n")?;
+
+        cwrite!(self, white; f, "modified: ")?;
+        cwrite!(self, bold; f, "{}", summary.modified_today)?;
+        cwrite!(self, bright_white; f, " today")?;
+        cwrite!(self, bright_black; f, ", ")?;
+        cwrite!(self, bold; f, "{}", summary.modified_this_week)?;
+        cwrite!(self, bright_blue; f, " this week")?;
+        cwrite!(self, bright_black; f, ", ")?;
+        cwrite!(self, bold; f, "{}", summary.modified_older)?;
+        cwrite!(self, bright_black; f, " older")
+    }
+}
+
+impl HasColor for SummaryDisplay<'_> {
+    fn has_color(&self) -> Option<bool> {
+        self.arguments.color
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+    use std::io::Result;
+
+    use time::macros::datetime;
+
+    use super::{AgeCutoffs, Summary};
+    use crate::Entry;
+
+    #[test]
+    fn ages_are_bucketed_in_the_local_week() -> Result<()> {
+        // A Wednesday, where the start of the day and of the week both fall on the previous day in UTC.
+        let cutoffs = AgeCutoffs::at(datetime!(2024-06-05 10:00 +9));
+        let directory = std::env::temp_dir().join(format!("rs-summary-{}", std::process::id()));
+        let times = [
+            ("today", datetime!(2024-06-05 01:00 +9)),
+            ("monday", datetime!(2024-06-03 05:00 +9)),
+            ("sunday", datetime!(2024-06-02 23:00 +9)),
+        ];
+        let mut summaries = Vec::with_capacity(times.len());
+
+        std::fs::create_dir_all(&directory)?;
+
+        for (name, modified) in times {
+            let path = directory.join(name);
+            let file = File::create(&path)?;
+            let mut summary = Summary::default();
+
+            file.set_modified(modified.into())?;
+            summary.add_age(&Entry::new(path, file.metadata()?), &cutoffs);
+            summaries.push((summary.modified_today, summary.modified_this_week, summary.modified_older));
+        }
+
+        std::fs::remove_dir_all(&directory)?;
+
+        assert_eq!(summaries, [(1, 0, 0), (0, 1, 0), (0, 0, 1)]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+use std::io::{Result, Write};
+
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+use super::{Displayer, HasColor, OwnerDisplay, PermissionsDisplay};
+use crate::{arguments::Arguments, Entry};
+
+/// Tabular output formats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TabularFormat {
+    /// Comma-separated values.
+    Csv,
+    /// Tab-separated values.
+    Tsv,
+}
+
+impl TabularFormat {
+    /// Returns the character used to separate fields.
+    #[must_use]
+    pub const fn delimiter(self) -> char {
+        match self {
+            Self::Csv => ',',
+            Self::Tsv => '\t',
+        }
+    }
+}
+
+/// Displays entries as rows of a spreadsheet-friendly table.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TabularDisplay<'ar> {
+    /// The program's arguments.
+    arguments: &'ar Arguments,
+    /// The output format.
+    format: TabularFormat,
+    /// The display used to resolve owners.
+    owner_display: OwnerDisplay<'ar>,
+}
+
+impl<'ar> TabularDisplay<'ar> {
+    /// The names of each column.
+    pub const COLUMNS: [&'static str; 6] = ["name", "size", "modified", "permissions", "owner", "type"];
+
+    /// Creates a new [`TabularDisplay`].
+    #[must_use]
+    pub const fn new(arguments: &'ar Arguments, format: TabularFormat) -> Self {
+        Self { arguments, format, owner_display: OwnerDisplay::new(arguments) }
+    }
+
+    /// Displays the table's header row.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the header could not be displayed.
+    pub fn show_header<W: Write>(&self, f: &mut W) -> Result<()> {
+        self.show_row(f, Self::COLUMNS)
+    }
+
+    /// Displays a row for each of the given entries.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if a row could not be displayed.
+    pub fn show_all<W: Write>(&self, f: &mut W, entries: &[Entry]) -> Result<()> {
+        entries.iter().try_for_each(|entry| self.show(f, entry))
+    }
+
+    /// Displays a row containing the given fields.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the row could not be displayed.
+    fn show_row<W, S>(&self, f: &mut W, fields: impl IntoIterator<Item = S>) -> Result<()>
+    where
+        W: Write,
+        S: AsRef<str>,
+    {
+        for (index, field) in fields.into_iter().enumerate() {
+            if index > 0 {
+                write!(f, "{}", self.format.delimiter())?;
+            }
+
+            self.show_field(f, field.as_ref())?;
+        }
+
+        f.write_all(b"\n")
+    }
+
+    /// Displays a single field, escaping it if necessary.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the field could not be displayed.
+    fn show_field<W: Write>(&self, f: &mut W, field: &str) -> Result<()> {
+        match self.format {
+            TabularFormat::Csv if field.contains([',', '"', '\n', '\r']) => {
+                write!(f, "\"{}\"", field.replace('"', "\"\""))
+            }
+            TabularFormat::Csv => f.write_all(field.as_bytes()),
+            TabularFormat::Tsv => {
+                for character in field.chars() {
+                    match character {
+                        '\\' => f.write_all(b"\\\\")?,
+                        '\t' => f.write_all(b"\\t")?,
+                        '\n' => f.write_all(b"\\n")?,
+                        '\r' => f.write_all(b"\\r")?,
+                        c => write!(f, "{c}")?,
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl HasColor for TabularDisplay<'_> {
+    fn has_color(&self) -> Option<bool> {
+        Some(false)
+    }
+}
+
+impl Displayer for TabularDisplay<'_> {
+    fn show<W: Write>(&self, f: &mut W, entry: &Entry) -> Result<()> {
+        let name = entry.path.file_name().unwrap_or(entry.path.as_os_str()).to_string_lossy();
+
+        let mut modified = OffsetDateTime::from(entry.data.modified()?);
+
+        if let Ok(offset) = UtcOffset::current_local_offset() {
+            modified = modified.to_offset(offset);
+        }
+
+        let mut permissions = Vec::new();
+
+        PermissionsDisplay::new(self.arguments).show_entry(&mut permissions, entry)?;
+
+        let kind = if entry.data.is_symlink() {
+            "symlink"
+        } else if entry.data.is_dir() {
+            "directory"
+        } else {
+            "file"
+        };
+
+        self.show_row(
+            f,
+            [
+                &*name,
+                itoa::Buffer::new().format(entry.data.len()),
+                &modified.format(&Rfc3339).expect("the well-known format is correctly defined"),
+                &String::from_utf8_lossy(&permissions),
+                &self.owner_display.get_owner(entry),
+                kind,
+            ],
+        )
+    }
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+use crate::arguments::Arguments;
+
+/// The decorative characters displayed within the program's output.
+///
+/// Every non-ASCII character that the program displays, other than within entry names, is chosen here.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Glyphs {
+    /// The tree connector displayed before an entry with later siblings.
+    pub branch: &'static str,
+    /// The tree connector displayed before an entry without later siblings.
+    pub last_branch: &'static str,
+    /// The tree connector displayed beneath an ancestor with later siblings, continuing its branch.
+    pub continuation: &'static str,
+    /// The tree connector displayed beneath an ancestor without later siblings.
+    pub blank: &'static str,
+    /// The marker displayed in place of characters removed from a truncated name.
+    pub ellipsis: &'static str,
+}
+
+impl Glyphs {
+    /// The glyphs displayed by default.
+    pub const UNICODE: Self =
+        Self {
+            branch: "├── ", last_branch: "└── ", continuation: "│   ", blank: "    ", ellipsis: "…"
+        };
+    /// The glyphs displayed when only ASCII characters are allowed.
+    pub const ASCII: Self =
+        Self { branch: "|-- ", last_branch: "`-- ", continuation: "|   ", blank: "    ", ellipsis: "..." };
+
+    /// Returns the glyphs selected by the given arguments.
+    #[must_use]
+    pub const fn new(arguments: &Arguments) -> Self {
+        if arguments.ascii {
+            Self::ASCII
+        } else {
+            Self::UNICODE
+        }
+    }
+}
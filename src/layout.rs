@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+use std::io::{Result, Write};
+
+/// The terminal width to assume if it cannot be detected.
+pub const DEFAULT_WIDTH: usize = 80;
+/// The environment variable that pins the terminal width, regardless of the real terminal.
+///
+/// This is only intended for testing, so that layouts are reproducible without a terminal.
+pub const TEST_WIDTH_VARIABLE: &str = "RS_TEST_WIDTH";
+/// The number of spaces to place between grid columns.
+const GRID_GAP: usize = 2;
+
+/// Layout types.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LayoutType {
+    /// Display one entry per line.
+    #[default]
+    SingleColumn,
+    /// Display entries in columns, filled top-to-bottom.
+    Grid,
+    /// Display entries in columns, filled left-to-right.
+    Across,
+    /// Display entries separated by commas, filling the terminal's width.
+    Commas,
+}
+
+/// The orders in which a grid's cells may be filled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GridDirection {
+    /// Fill each column from top to bottom before moving onto the next.
+    #[default]
+    Down,
+    /// Fill each row from left to right before moving onto the next.
+    Across,
+}
+
+impl GridDirection {
+    /// Returns the index of the cell at the given position within a grid of the given dimensions.
+    const fn index(self, row: usize, column: usize, rows: usize, columns: usize) -> usize {
+        match self {
+            Self::Down => (column * rows) + row,
+            Self::Across => (row * columns) + column,
+        }
+    }
+}
+
+/// Returns the width of the terminal, falling back to the `COLUMNS` environment variable or [`DEFAULT_WIDTH`].
+///
+/// A width given by [`TEST_WIDTH_VARIABLE`] takes precedence over all of these.
+#[must_use]
+pub fn terminal_width() -> usize {
+    if let Some(width) = std::env::var(TEST_WIDTH_VARIABLE).ok().and_then(|v| v.parse().ok()).filter(|v| *v > 0) {
+        return width;
+    }
+    if let Some((terminal_size::Width(width), _)) = terminal_size::terminal_size() {
+        return usize::from(width);
+    }
+
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .filter(|v| *v > 0)
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+/// Returns the number of visible characters within the given rendered text, ignoring any ANSI escape sequences.
+#[must_use]
+pub fn visible_width(text: &[u8]) -> usize {
+    let text = String::from_utf8_lossy(text);
+    let mut characters = text.chars();
+    let mut width = 0;
+
+    while let Some(character) = characters.next() {
+        if character != '\x1B' {
+            width += 1;
+
+            continue;
+        }
+
+        // Skip control sequences up to and including their final byte.
+        if characters.next() == Some('[') {
+            characters.by_ref().find(|c| ('\x40'..='\x7E').contains(c));
+        }
+    }
+
+    width
+}
+
+/// Writes the given rendered cells into a grid that fits within the given width, filled in the given direction.
+///
+/// Cells are placed in exactly the order that they are given, starting from the top-left corner, so any reversal must
+/// already be applied to the cells beforehand.
+///
+/// # Errors
+///
+/// This function will return an error if the grid could not be written.
+pub fn write_grid<W: Write>(f: &mut W, cells: &[Box<[u8]>], width: usize, direction: GridDirection) -> Result<()> {
+    if cells.is_empty() {
+        return Ok(());
+    }
+
+    let widths = cells.iter().map(|cell| self::visible_width(cell)).collect::<Box<[_]>>();
+    let (rows, column_widths) = self::grid_dimensions(&widths, width, direction);
+    let columns = column_widths.len();
+
+    for row in 0..rows {
+        for (column, column_width) in column_widths.iter().enumerate() {
+            let index = direction.index(row, column, rows, columns);
+            let Some(cell) = cells.get(index) else { break };
+
+            f.write_all(cell)?;
+
+            // Only pad cells that are followed by another cell on the same row.
+            if column + 1 < columns && cells.get(direction.index(row, column + 1, rows, columns)).is_some() {
+                f.write_all(&b" ".repeat(column_width - widths[index] + GRID_GAP))?;
+            }
+        }
+
+        f.write_all(b"\n")?;
+    }
+
+    Ok(())
+}
+
+/// Returns the number of rows and the width of each column for a grid containing cells of the given widths, filled in
+/// the given direction.
+fn grid_dimensions(widths: &[usize], width: usize, direction: GridDirection) -> (usize, Box<[usize]>) {
+    for rows in 1..=widths.len() {
+        let columns = widths.len().div_ceil(rows);
+        let column_widths = match direction {
+            GridDirection::Down => {
+                widths.chunks(rows).map(|column| column.iter().copied().max().unwrap_or(0)).collect()
+            }
+            GridDirection::Across => (0..columns)
+                .map(|column| widths.iter().skip(column).step_by(columns).copied().max().unwrap_or(0))
+                .collect::<Box<[_]>>(),
+        };
+        let total = column_widths.iter().sum::<usize>() + (GRID_GAP * (column_widths.len() - 1));
+
+        if total <= width {
+            // Filling across may need fewer rows than were tried, as every row but the last is full. Filling down must
+            // keep the rows that were tried, as the column widths were measured using them.
+            let rows = match direction {
+                GridDirection::Down => rows,
+                GridDirection::Across => widths.len().div_ceil(columns),
+            };
+
+            return (rows, column_widths);
+        }
+    }
+
+    (widths.len(), Box::new([widths.iter().copied().max().unwrap_or(0)]))
+}
+
+/// Writes the given rendered cells separated by commas, wrapping lines that would exceed the given width.
+///
+/// # Errors
+///
+/// This function will return an error if the cells could not be written.
+pub fn write_commas<W: Write>(f: &mut W, cells: &[Box<[u8]>], width: usize) -> Result<()> {
+    let mut line_width = 0;
+
+    for (index, cell) in cells.iter().enumerate() {
+        let is_last = index == cells.len() - 1;
+        // Account for the trailing comma.
+        let cell_width = self::visible_width(cell) + usize::from(!is_last);
+
+        if line_width > 0 {
+            if line_width + 1 + cell_width > width {
+                f.write_all(b"\n")?;
+
+                line_width = 0;
+            } else {
+                f.write_all(b" ")?;
+
+                line_width += 1;
+            }
+        }
+
+        f.write_all(cell)?;
+
+        if !is_last {
+            f.write_all(b",")?;
+        }
+
+        line_width += cell_width;
+    }
+
+    if !cells.is_empty() {
+        f.write_all(b"\n")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{write_grid, GridDirection};
+
+    /// Returns the grid written for cells containing the given names.
+    fn grid(names: &[&str], width: usize, direction: GridDirection) -> String {
+        let cells = names.iter().map(|name| name.as_bytes().into()).collect::<Box<[Box<[u8]>]>>();
+        let mut output = Vec::new();
+
+        write_grid(&mut output, &cells, width, direction).expect("writing to a vector should not fail");
+
+        String::from_utf8(output).expect("the grid should be valid UTF-8")
+    }
+
+    #[test]
+    fn down_and_across_orderings() {
+        let names = ["a", "b", "c", "d", "e"];
+
+        assert_eq!(grid(&names, 10, GridDirection::Down), "a  c  e\nb  d\n");
+        assert_eq!(grid(&names, 10, GridDirection::Across), "a  b  c\nd  e\n");
+    }
+
+    #[test]
+    fn down_keeps_the_rows_that_were_measured() {
+        let names = [30, 1, 1, 2, 30, 2, 2, 3, 2, 3, 3, 1].map(|length| "x".repeat(length));
+        let names = names.iter().map(String::as_str).collect::<Box<[_]>>();
+        let output = grid(&names, 44, GridDirection::Down);
+
+        assert_eq!(output.lines().count(), 5);
+        assert!(output.lines().all(|line| line.len() <= 44));
+    }
+}
@@ -25,20 +25,41 @@
 
 extern crate alloc;
 
+use alloc::collections::VecDeque;
+use core::cell::RefCell;
+use core::time::Duration;
+use std::collections::HashSet;
 use std::fs::{DirEntry, Metadata, ReadDir};
-use std::io::{Result, StderrLock, StdoutLock, Write};
+use std::io::{IsTerminal, Result, StderrLock, StdoutLock, Write};
 use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
 
 use arguments::Arguments;
-use display::{Displayer, HeaderDisplay, ModifiedDisplay, NameDisplay, OwnerDisplay, PermissionsDisplay, SizeDisplay};
-use sort::{HoistType, SortType, Sorter};
+use display::{
+    AgeCutoffs, Columns, DetailsDisplay, Displayer, GitStatus, GitStatusDisplay, HeaderDisplay, SizeDisplay, Summary,
+    SummaryDisplay, TabularDisplay,
+};
+use glyphs::Glyphs;
+use is_executable::IsExecutable;
+use layout::{GridDirection, LayoutType};
+use sort::{GroupType, SortType, Sorter};
 
 /// Defines the application's command-line arguments and handles parsing.
 pub mod arguments;
+/// Generates shell completion scripts.
+pub mod completions;
 /// Provides interfaces for displaying information.
 pub mod display;
+/// Defines the decorative characters displayed within the program's output.
+pub mod glyphs;
+/// Provides interfaces for laying out listings.
+pub mod layout;
 /// Provides interfaces for sorting entries.
 pub mod sort;
+/// Re-displays listings whenever the listed directories change.
+#[cfg(feature = "watch")]
+pub mod watch;
 
 /// A file system entry.
 ///
@@ -60,6 +81,22 @@ impl Entry {
     }
 }
 
+impl Entry {
+    /// Returns the kind of this [`Entry`].
+    #[must_use]
+    pub fn kind(&self) -> EntryKind {
+        if self.data.is_symlink() {
+            EntryKind::Symlink
+        } else if self.data.is_dir() {
+            EntryKind::Directory
+        } else if self.path.is_executable() {
+            EntryKind::Executable
+        } else {
+            EntryKind::File
+        }
+    }
+}
+
 impl TryFrom<DirEntry> for Entry {
     type Error = std::io::Error;
 
@@ -68,21 +105,132 @@ impl TryFrom<DirEntry> for Entry {
     }
 }
 
+/// The kind of a file system entry.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EntryKind {
+    /// A directory.
+    Directory,
+    /// A regular file.
+    File,
+    /// A symbolic link.
+    Symlink,
+    /// An executable file.
+    Executable,
+}
+
+impl EntryKind {
+    /// Every entry kind, in the order that they should be grouped.
+    pub const ALL: [Self; 4] = [Self::Directory, Self::File, Self::Symlink, Self::Executable];
+
+    /// Returns the plural name of this entry kind.
+    #[must_use]
+    pub const fn plural_name(self) -> &'static str {
+        match self {
+            Self::Directory => "Directories",
+            Self::File => "Files",
+            Self::Symlink => "Symlinks",
+            Self::Executable => "Executables",
+        }
+    }
+}
+
+/// The severity of the problems encountered while listing entries, reported as the program's exit code.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Status {
+    /// Every entry was listed successfully.
+    #[default]
+    Success = 0,
+    /// An individual entry could not be read or sorted.
+    Minor = 1,
+    /// A requested path could not be listed.
+    Serious = 2,
+}
+
+impl Status {
+    /// Raises this status to the given status if it is more severe.
+    pub fn escalate(&mut self, status: Self) {
+        *self = (*self).max(status);
+    }
+}
+
+impl From<Status> for ExitCode {
+    fn from(value: Status) -> Self {
+        Self::from(value as u8)
+    }
+}
+
+/// Tracks the directories that have already been traversed, preventing symbolic link cycles from recursing forever.
+#[derive(Clone, Debug, Default)]
+pub struct VisitedDirectories {
+    /// The canonicalized paths of every visited directory.
+    paths: HashSet<PathBuf>,
+}
+
+impl VisitedDirectories {
+    /// Marks the given directory as visited, returning `false` if it had already been visited.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the directory's path could not be canonicalized.
+    pub fn visit(&mut self, directory: impl AsRef<Path>) -> Result<bool> {
+        Ok(self.paths.insert(std::fs::canonicalize(directory)?))
+    }
+}
+
+#[cfg(target_family = "unix")]
+thread_local! {
+    /// The device and inode numbers of every hard-linked file listed so far, used to hide additional hard links.
+    static LISTED_HARDLINKS: RefCell<HashSet<(u64, u64)>> = RefCell::default();
+}
+
+/// Returns whether the given entry is an additional hard link to a file that has already been listed, marking it as
+/// listed otherwise.
+#[cfg(target_family = "unix")]
+fn is_listed_hardlink(entry: &Entry) -> bool {
+    use std::os::unix::fs::MetadataExt;
+
+    // Directories can't be hard-linked, and files with a single link can't have been listed through another path.
+    if entry.data.is_dir() || entry.data.nlink() < 2 {
+        return false;
+    }
+
+    LISTED_HARDLINKS.with_borrow_mut(|listed| !listed.insert((entry.data.dev(), entry.data.ino())))
+}
+
+/// Returns whether the given entry is an additional hard link to a file that has already been listed.
+///
+/// Hard links can only be identified on Unix, so this always returns `false` elsewhere.
+#[cfg(not(target_family = "unix"))]
+const fn is_listed_hardlink(_: &Entry) -> bool {
+    false
+}
+
+/// Returns whether the given entry should be descended into when listing recursively.
+#[must_use]
+pub fn is_traversable(arguments: &Arguments, entry: &Entry) -> bool {
+    entry.data.is_dir() || (arguments.follow_symlinks && entry.data.is_symlink() && entry.path.is_dir())
+}
+
 /// Returns an iterator over entries for the given path.
 ///
+/// Paths that could not be read are reported to standard error.
+///
 /// # Errors
 ///
-/// This function will return an error if the iterator could not be created.
+/// This function will return an error if a message could not be written.
 fn entries_iterator(
     stdout: &mut StdoutLock,
     stderr: &mut StderrLock,
+    status: &mut Status,
     path: impl AsRef<Path>,
 ) -> Result<Option<ReadDir>> {
     let path = path.as_ref();
 
-    if !path.try_exists()? {
+    if !path.try_exists().unwrap_or(true) {
         writeln!(stderr, "Invalid path '{}'.", path.to_string_lossy())?;
 
+        status.escalate(Status::Serious);
+
         return Ok(None);
     }
     if path.is_file() {
@@ -91,15 +239,30 @@ fn entries_iterator(
         return Ok(None);
     }
 
-    if path.is_symlink() {
-        self::entries_iterator(stdout, stderr, std::fs::canonicalize(path)?)
+    let iterator = if path.is_symlink() {
+        match std::fs::canonicalize(path) {
+            Ok(path) => return self::entries_iterator(stdout, stderr, status, path),
+            Err(error) => Err(error),
+        }
     } else {
-        std::fs::read_dir(path).map(Some)
-    }
+        std::fs::read_dir(path)
+    };
+
+    // A path that can't be listed, such as a named pipe or an unreadable directory, shouldn't prevent the others from
+    // being listed.
+    iterator.map(Some).or_else(|error| {
+        writeln!(stderr, "Failed to read '{}': {error}", path.to_string_lossy())?;
+
+        status.escalate(Status::Serious);
+
+        Ok(None)
+    })
 }
 
 /// Returns a list of resolved entries to list.
 ///
+/// Entries that could not be resolved are reported to standard error and skipped.
+///
 /// # Panics
 ///
 /// Panics if an error message could not be written to standard error during sorting.
@@ -111,13 +274,65 @@ pub fn entries_list(
     arguments: &Arguments,
     stdout: &mut StdoutLock,
     stderr: &mut StderrLock,
+    status: &mut Status,
     directory: impl AsRef<Path>,
 ) -> Result<Option<Box<[Entry]>>> {
-    let Some(iterator) = self::entries_iterator(stdout, stderr, directory)? else {
+    Ok(self::counted_entries_list(arguments, stdout, stderr, status, directory)?.map(|(entries, _)| entries))
+}
+
+/// Returns a list of resolved entries to list, alongside the number of entries that were hidden by requested filters.
+///
+/// Entries that could not be resolved are reported to standard error and skipped.
+///
+/// # Panics
+///
+/// Panics if an error message could not be written to standard error during sorting.
+///
+/// # Errors
+///
+/// This function will return an error if the entries could not be resolved.
+pub fn counted_entries_list(
+    arguments: &Arguments,
+    stdout: &mut StdoutLock,
+    stderr: &mut StderrLock,
+    status: &mut Status,
+    directory: impl AsRef<Path>,
+) -> Result<Option<(Box<[Entry]>, usize)>> {
+    /// How long collection may take before a progress message is shown.
+    const PROGRESS_DELAY: Duration = Duration::from_millis(500);
+
+    let Some(iterator) = self::entries_iterator(stdout, stderr, status, directory)? else {
         return Ok(None);
     };
 
-    let mut entries = iterator.map(|v| v.and_then(Entry::try_from)).collect::<Result<Vec<_>>>()?;
+    let start = Instant::now();
+    let show_progress = stderr.is_terminal();
+    let mut progress_shown = false;
+    let mut entries = Vec::new();
+
+    for entry in iterator {
+        if show_progress && !progress_shown && start.elapsed() >= PROGRESS_DELAY {
+            stderr.write_all(b"Reading directory...")?;
+            stderr.flush()?;
+
+            progress_shown = true;
+        }
+
+        match entry.and_then(Entry::try_from) {
+            Ok(entry) => entries.push(entry),
+            Err(error) => {
+                writeln!(stderr, "Failed to read entry: {error}")?;
+
+                status.escalate(Status::Minor);
+            }
+        }
+    }
+
+    // The progress message is transient, so it's cleared before anything else is written.
+    if progress_shown {
+        stderr.write_all(b"\r\x1b[K")?;
+        stderr.flush()?;
+    }
 
     if !arguments.show_hidden {
         entries.retain(|entry| {
@@ -127,96 +342,725 @@ pub fn entries_list(
         });
     }
 
-    entries.sort_unstable_by(|a, b| {
+    // Hidden files are omitted by default rather than by choice, so only the filters that were requested are counted.
+    let unfiltered = entries.len();
+
+    if let Some(entry_kinds) = &arguments.entry_kinds {
+        entries.retain(|entry| entry_kinds.contains(&entry.kind()));
+    }
+    if arguments.only_hidden {
+        entries.retain(|entry| entry.path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.')));
+    }
+    if arguments.hide_backups {
+        entries.retain(|entry| {
+            let Some(name) = entry.path.file_name() else { return true };
+
+            !name.to_string_lossy().ends_with('~')
+        });
+    }
+    // Entries without a known modification time can't be shown to match, so they're hidden by either filter. A
+    // directory's modification time doesn't reflect those of its contents, so directories are kept to be descended into.
+    if let Some(cutoff) = arguments.newer_than {
+        entries.retain(|entry| entry.data.is_dir() || entry.data.modified().is_ok_and(|modified| modified > cutoff));
+    }
+    if let Some(cutoff) = arguments.older_than {
+        entries.retain(|entry| entry.data.is_dir() || entry.data.modified().is_ok_and(|modified| modified < cutoff));
+    }
+    // Directory sizes aren't comparable to file sizes, so directories are kept unless requested otherwise.
+    if arguments.min_size.is_some() || arguments.max_size.is_some() {
+        let range = arguments.min_size.unwrap_or(u64::MIN)..=arguments.max_size.unwrap_or(u64::MAX);
+
+        entries.retain(|entry| {
+            if entry.data.is_dir() {
+                !arguments.size_filter_dirs
+            } else {
+                range.contains(&entry.data.len())
+            }
+        });
+    }
+
+    let filtered = unfiltered - entries.len();
+
+    self::sort_entries(arguments, stderr, status, &mut entries);
+
+    // Hard links are deduplicated after sorting, so that the link that would be displayed first is the one that's kept.
+    if arguments.dedup_hardlinks {
+        entries.retain(|entry| !self::is_listed_hardlink(entry));
+    }
+
+    Ok(Some((entries.into_boxed_slice(), filtered)))
+}
+
+/// Returns a list of resolved entries to list within a subdirectory, like [`counted_entries_list`].
+///
+/// Like `ls -R`, failing to list a subdirectory is only a minor problem, unlike failing to list a path that was given.
+///
+/// # Errors
+///
+/// This function will return an error if the entries could not be resolved.
+fn subdirectory_entries_list(
+    arguments: &Arguments,
+    stdout: &mut StdoutLock,
+    stderr: &mut StderrLock,
+    status: &mut Status,
+    directory: &Path,
+) -> Result<Option<(Box<[Entry]>, usize)>> {
+    let mut listing = Status::default();
+    let list = self::counted_entries_list(arguments, stdout, stderr, &mut listing, directory)?;
+
+    status.escalate(listing.min(Status::Minor));
+
+    Ok(list)
+}
+
+/// Sorts the given list of entries.
+///
+/// # Panics
+///
+/// Panics if an error message could not be written to standard error during sorting.
+pub fn sort_entries(arguments: &Arguments, stderr: &mut StderrLock, status: &mut Status, entries: &mut [Entry]) {
+    // Unsorted entries keep the order that they were read in, so reversal is applied to that order directly.
+    if arguments.sort_reversed && arguments.sort_functions.iter().all(|function| *function == SortType::None) {
+        entries.reverse();
+    }
+
+    // Some methods are documented as ordering by name afterwards, which only matters if they're the last method given.
+    let tie_break = arguments
+        .sort_functions
+        .last()
+        .filter(|function| function.is_tied_by_name())
+        .map(|_| SortType::Name { case_sensitive: false });
+
+    // The sort is stable so that unsorted entries keep their order within each hoisted group.
+    entries.sort_by(|a, b| {
         let hoisted = arguments.hoist_function.sort(a, b).unwrap_or_else(|error| {
             writeln!(stderr, "Failed to hoist entries: {error}").unwrap();
 
+            status.escalate(Status::Minor);
+
             core::cmp::Ordering::Equal
         });
-        let sorted = arguments.sort_function.sort(a, b).unwrap_or_else(|error| {
-            writeln!(stderr, "Failed to sort entries: {error}").unwrap();
+        // Each sorting method only breaks ties left by the previous ones.
+        let functions = arguments.sort_functions.iter().chain(tie_break.as_ref());
+        let sorted = functions.fold(core::cmp::Ordering::Equal, |ordering, function| {
+            ordering.then_with(|| {
+                function.sort(a, b).unwrap_or_else(|error| {
+                    writeln!(stderr, "Failed to sort entries: {error}").unwrap();
 
-            core::cmp::Ordering::Equal
+                    status.escalate(Status::Minor);
+
+                    core::cmp::Ordering::Equal
+                })
+            })
         });
 
+        // Reversal applies to the entire compound ordering, not to each individual method.
         hoisted.then(if arguments.sort_reversed { sorted.reverse() } else { sorted })
     });
+}
+
+/// Displays a list of entries, returning a tally of the displayed entry types.
+///
+/// # Errors
+///
+/// This function will return an error if the listing fails to display.
+pub fn show(
+    arguments: &Arguments,
+    stdout: &mut StdoutLock,
+    iterator: impl IntoIterator<Item = Entry>,
+) -> Result<Summary> {
+    let Some(max_entries) = arguments.max_entries else {
+        return match arguments.group_by {
+            GroupType::None if arguments.group_gap => self::show_hoisted(arguments, stdout, iterator),
+            GroupType::None => self::show_entries(arguments, stdout, iterator),
+            GroupType::Type => self::show_grouped(arguments, stdout, iterator),
+        };
+    };
+
+    let mut entries = iterator.into_iter().collect::<Vec<_>>();
+    let hidden = entries.len().saturating_sub(max_entries);
+
+    // The entries are already sorted, so this keeps the first entries in the chosen order.
+    entries.truncate(max_entries);
+
+    let summary = match arguments.group_by {
+        GroupType::None if arguments.group_gap => self::show_hoisted(arguments, stdout, entries)?,
+        GroupType::None => self::show_entries(arguments, stdout, entries)?,
+        GroupType::Type => self::show_grouped(arguments, stdout, entries)?,
+    };
+
+    if hidden > 0 {
+        cwrite!(arguments, bright_black; stdout, "... and {hidden} more")?;
+
+        stdout.write_all(b"\n")?;
+    }
+
+    Ok(summary)
+}
+
+/// Displays the header of the given directory, optionally preceded by the directory's own detail columns and followed by
+/// the number of entries listed beneath it.
+///
+/// Symbolic links are displayed by their own path and details, unless `--dereference-args` is given, in which case both
+/// describe the resolved directory instead.
+///
+/// # Errors
+///
+/// This function will return an error if the header fails to display.
+pub fn show_header(
+    arguments: &Arguments,
+    stdout: &mut StdoutLock,
+    stderr: &mut StderrLock,
+    status: &mut Status,
+    directory: &Path,
+    count: usize,
+) -> Result<()> {
+    let header_display = HeaderDisplay::new(arguments);
+    let header_display = if arguments.header_counts { header_display.with_count(count) } else { header_display };
+    let (path, data) = if arguments.dereference_args && directory.is_symlink() {
+        let path = directory.canonicalize().unwrap_or_else(|_| directory.to_path_buf());
+        let data = path.metadata();
+
+        (path, data)
+    } else {
+        (directory.to_path_buf(), directory.symlink_metadata())
+    };
+
+    // Failing to read the directory's own details, such as for a dangling link, shouldn't abort the entire listing.
+    let data = match data {
+        Ok(data) => data,
+        Err(error) => {
+            writeln!(stderr, "Failed to read details of '{}': {error}", path.to_string_lossy())?;
+
+            status.escalate(Status::Minor);
+
+            header_display.show_path(stdout, &path)?;
+
+            return stdout.write_all(b"\n");
+        }
+    };
+
+    let entry = Entry::new(path, data);
+
+    if arguments.header_details {
+        DetailsDisplay::new(arguments).show(stdout, &entry)?;
+    }
+
+    header_display.show(stdout, &entry)?;
+
+    stdout.write_all(b"\n")?;
+
+    self::show_git_status(arguments, stdout, directory)
+}
+
+/// Displays a summary of the status of the git repository containing the given directory, if requested and if the
+/// directory is within a repository.
+///
+/// # Errors
+///
+/// This function will return an error if the summary fails to display.
+pub fn show_git_status(arguments: &Arguments, stdout: &mut StdoutLock, directory: &Path) -> Result<()> {
+    if !arguments.git_status_summary {
+        return Ok(());
+    }
+
+    let Some(status) = GitStatus::read(directory) else { return Ok(()) };
+
+    GitStatusDisplay::new(arguments).show(stdout, &status)?;
+
+    stdout.write_all(b"\n")
+}
+
+/// Displays a list of entries in labeled sections by their kind, returning a tally of the displayed entry types.
+///
+/// # Errors
+///
+/// This function will return an error if the listing fails to display.
+pub fn show_grouped(
+    arguments: &Arguments,
+    stdout: &mut StdoutLock,
+    iterator: impl IntoIterator<Item = Entry>,
+) -> Result<Summary> {
+    let header_display = HeaderDisplay::new(arguments);
+    let entries = iterator.into_iter().collect::<Box<[_]>>();
+    let mut summary = Summary::default();
+    let mut is_first = true;
+
+    for kind in EntryKind::ALL {
+        let group = entries.iter().filter(|entry| entry.kind() == kind).cloned().collect::<Box<[_]>>();
+
+        if group.is_empty() {
+            continue;
+        }
+        if !is_first {
+            stdout.write_all(b"\n")?;
+        }
+
+        header_display.show_label(stdout, kind.plural_name())?;
+
+        stdout.write_all(b"\n")?;
+
+        summary += self::show_entries(arguments, stdout, group)?;
+        is_first = false;
+    }
+
+    Ok(summary)
+}
+
+/// Displays a list of sorted entries with a blank line between each hoisted group, returning a tally of the displayed
+/// entry types.
+///
+/// # Errors
+///
+/// This function will return an error if the listing fails to display.
+pub fn show_hoisted(
+    arguments: &Arguments,
+    stdout: &mut StdoutLock,
+    iterator: impl IntoIterator<Item = Entry>,
+) -> Result<Summary> {
+    let entries = iterator.into_iter().collect::<Box<[_]>>();
+    let mut summary = Summary::default();
 
-    Ok(Some(entries.into_boxed_slice()))
+    // Hoisting failures were already reported while sorting, so they're treated as belonging to the same group here.
+    let groups = entries.chunk_by(|a, b| arguments.hoist_function.sort(a, b).map_or(true, core::cmp::Ordering::is_eq));
+
+    for (index, group) in groups.enumerate() {
+        if index > 0 {
+            stdout.write_all(b"\n")?;
+        }
+
+        summary += self::show_entries(arguments, stdout, group.iter().cloned())?;
+    }
+
+    Ok(summary)
 }
 
-/// Displays a list of entries.
+/// Displays a list of entries, returning a tally of the displayed entry types.
 ///
 /// # Errors
 ///
 /// This function will return an error if the listing fails to display.
-pub fn show(arguments: &Arguments, stdout: &mut StdoutLock, iterator: impl IntoIterator<Item = Entry>) -> Result<()> {
-    let name_display = NameDisplay::new(arguments);
-    let permissions_display = arguments.show_permissions.then(|| PermissionsDisplay::new(arguments));
-    let size_display = arguments.show_sizes.then(|| SizeDisplay::new(arguments));
-    let modified_display = arguments.show_modified.then(|| ModifiedDisplay::new(arguments));
-    let owner_display = arguments.show_owner.then(|| OwnerDisplay::new(arguments));
+pub fn show_entries(
+    arguments: &Arguments,
+    stdout: &mut StdoutLock,
+    iterator: impl IntoIterator<Item = Entry>,
+) -> Result<Summary> {
+    let row_display = Columns::row(arguments);
+    let age_cutoffs = arguments.summary_ages.then(AgeCutoffs::now);
+    let mut summary = Summary::default();
+    let mut cells = Vec::new();
+    let mut cell = Vec::new();
 
     for ref entry in iterator {
-        if let Some(ref displayer) = permissions_display {
-            displayer.show(stdout, entry)?;
+        summary.add(entry);
 
-            stdout.write_all(b" ")?;
-        };
-        if let Some(ref displayer) = size_display {
-            displayer.show(stdout, entry)?;
+        if let Some(ref age_cutoffs) = age_cutoffs {
+            summary.add_age(entry, age_cutoffs);
+        }
+
+        row_display.show(&mut cell, entry)?;
+
+        // Single-column listings are written immediately, while other layouts need every cell to be measured first.
+        if arguments.layout == LayoutType::SingleColumn {
+            stdout.write_all(&cell)?;
+            stdout.write_all(b"\n")?;
+
+            cell.clear();
+        } else {
+            cells.push(core::mem::take(&mut cell).into_boxed_slice());
+        }
+    }
+
+    match arguments.layout {
+        LayoutType::SingleColumn => {}
+        LayoutType::Grid => {
+            self::layout::write_grid(stdout, &cells, self::layout::terminal_width(), GridDirection::Down)?;
+        }
+        LayoutType::Across => {
+            self::layout::write_grid(stdout, &cells, self::layout::terminal_width(), GridDirection::Across)?;
+        }
+        LayoutType::Commas => self::layout::write_commas(stdout, &cells, self::layout::terminal_width())?,
+    }
+
+    Ok(summary)
+}
+
+/// Displays the given directory and all of its subdirectories, returning a tally of the displayed entry types.
+///
+/// Directories are listed depth-first by default, or breadth-first if requested, each beneath its own header. Only
+/// directories that aren't within the given counted directories are added to the returned tally, and are then marked as
+/// counted, so that paths that overlap aren't counted twice.
+///
+/// # Errors
+///
+/// This function will return an error if the listing fails to display.
+pub fn show_recursive(
+    arguments: &Arguments,
+    stdout: &mut StdoutLock,
+    stderr: &mut StderrLock,
+    status: &mut Status,
+    counted: &mut VisitedDirectories,
+    directory: &Path,
+) -> Result<Summary> {
+    let summary_display = SummaryDisplay::new(arguments);
+    let mut visited = VisitedDirectories::default();
+    let mut pending = VecDeque::from([directory.to_path_buf()]);
+    let mut total = Summary::default();
+    let mut is_first = true;
+
+    while let Some(directory) = pending.pop_front() {
+        if !visited.visit(&directory)? {
+            writeln!(stderr, "Not listing already listed directory '{}'.", directory.to_string_lossy())?;
+
+            status.escalate(Status::Minor);
+
+            continue;
+        }
 
-            stdout.write_all(b" ")?;
+        let list = if is_first {
+            self::counted_entries_list(arguments, stdout, stderr, status, &directory)?
+        } else {
+            self::subdirectory_entries_list(arguments, stdout, stderr, status, &directory)?
         };
-        if let Some(ref displayer) = modified_display {
-            displayer.show(stdout, entry)?;
+        let Some((entries, filtered)) = list else {
+            continue;
+        };
+
+        if !is_first {
+            stdout.write_all(b"\n")?;
+        }
+
+        self::show_header(arguments, stdout, stderr, status, &directory, entries.len())?;
+
+        let subdirectories = entries.iter().filter(|entry| self::is_traversable(arguments, entry));
 
-            stdout.write_all(b" ")?;
+        if arguments.breadth_first {
+            pending.extend(subdirectories.map(|entry| entry.path.clone()));
+        } else {
+            // Subdirectories are pushed in reverse so that they're popped in the order that they were displayed.
+            for entry in subdirectories.rev() {
+                pending.push_front(entry.path.clone());
+            }
+        }
+
+        let mut summary = self::show(arguments, stdout, entries)?;
+
+        summary.filtered = filtered;
+
+        if arguments.show_summary {
+            summary_display.show(stdout, &summary)?;
+
+            stdout.write_all(b"\n")?;
+        }
+
+        if counted.visit(&directory)? {
+            total += summary;
+        }
+        is_first = false;
+    }
+
+    Ok(total)
+}
+
+/// Displays the given path and all of its descendants as a tree, returning a tally of the displayed entry types.
+///
+/// Each entry is written as soon as it's visited, so only the remaining entries of the directories along the current
+/// branch are held in memory, rather than the entire tree.
+///
+/// # Errors
+///
+/// This function will return an error if the tree fails to display.
+pub fn show_tree(
+    arguments: &Arguments,
+    stdout: &mut StdoutLock,
+    stderr: &mut StderrLock,
+    status: &mut Status,
+    root: &Path,
+) -> Result<Summary> {
+    let row_display = Columns::row(arguments);
+    let glyphs = Glyphs::new(arguments);
+    let mut visited = VisitedDirectories::default();
+    let mut summary = Summary::default();
+    // The entries that are yet to be displayed within each directory along the current branch, from the root down.
+    let mut branch: Vec<alloc::vec::IntoIter<Entry>> = Vec::new();
+
+    cwrite!(arguments, bright_blue; stdout, "{}", root.to_string_lossy())?;
+
+    stdout.write_all(b"\n")?;
+
+    self::show_git_status(arguments, stdout, root)?;
+
+    if root.is_dir() && visited.visit(root)? {
+        branch.extend(self::entries_list(arguments, stdout, stderr, status, root)?.map(|v| v.into_vec().into_iter()));
+    }
+
+    while !branch.is_empty() {
+        let Some(entry) = branch.last_mut().and_then(Iterator::next) else {
+            branch.pop();
+
+            continue;
         };
-        if let Some(ref displayer) = owner_display {
-            displayer.show(stdout, entry)?;
 
-            stdout.write_all(b" ")?;
+        // A directory with no remaining entries has displayed its last child, so its branch line is no longer drawn.
+        for (depth, remaining) in branch.iter().enumerate() {
+            let connector = match (depth == branch.len() - 1, remaining.len() == 0) {
+                (false, false) => glyphs.continuation,
+                (false, true) => glyphs.blank,
+                (true, false) => glyphs.branch,
+                (true, true) => glyphs.last_branch,
+            };
+
+            cwrite!(arguments, bright_black; stdout, "{connector}")?;
         }
 
-        name_display.show(stdout, entry)?;
+        row_display.show(stdout, &entry)?;
 
         stdout.write_all(b"\n")?;
+
+        summary.add(&entry);
+
+        if !self::is_traversable(arguments, &entry) {
+            continue;
+        }
+        if !visited.visit(&entry.path)? {
+            writeln!(stderr, "Not listing already listed directory '{}'.", entry.path.to_string_lossy())?;
+
+            status.escalate(Status::Minor);
+
+            continue;
+        }
+
+        if let Some((entries, _)) = self::subdirectory_entries_list(arguments, stdout, stderr, status, &entry.path)? {
+            branch.push(entries.into_vec().into_iter());
+        }
     }
 
-    Ok(())
+    Ok(summary)
 }
 
-/// The program's entry-point.
+/// Displays every given path as a tree.
 ///
 /// # Errors
 ///
-/// This function will return an error if the program's execution fails in an unrecoverable manner.
-pub fn main() -> Result<()> {
-    let mut arguments = self::arguments::parse();
+/// This function will return an error if the trees could not be written.
+pub fn show_trees(
+    arguments: &Arguments,
+    stdout: &mut StdoutLock,
+    stderr: &mut StderrLock,
+    status: &mut Status,
+) -> Result<()> {
+    let summary_display = SummaryDisplay::new(arguments);
+    let current_directory = [Path::new(".").into()];
+    let paths = if arguments.paths.is_empty() { &current_directory[..] } else { &arguments.paths[..] };
+    let mut total = Summary::default();
+
+    for (index, path) in paths.iter().enumerate() {
+        if index > 0 {
+            stdout.write_all(b"\n")?;
+        }
+
+        let summary = self::show_tree(arguments, stdout, stderr, status, path)?;
+
+        if arguments.show_summary {
+            summary_display.show(stdout, &summary)?;
+
+            stdout.write_all(b"\n")?;
+        }
+
+        total += summary;
+    }
+
+    if arguments.grand_total {
+        self::show_grand_total(arguments, stdout, &total)?;
+    }
+
+    stdout.flush()
+}
+
+/// Displays every listed entry as a row of a table.
+///
+/// # Errors
+///
+/// This function will return an error if the table fails to display.
+pub fn show_tabular(
+    arguments: &Arguments,
+    display: &TabularDisplay,
+    stdout: &mut StdoutLock,
+    stderr: &mut StderrLock,
+    status: &mut Status,
+) -> Result<()> {
+    display.show_header(stdout)?;
+
+    if arguments.paths.is_empty() {
+        if let Some(entries) = self::entries_list(arguments, stdout, stderr, status, std::env::current_dir()?)? {
+            display.show_all(stdout, &entries)?;
+        }
+
+        return stdout.flush();
+    }
+
+    for path in &arguments.paths {
+        if path.is_file() {
+            display.show(stdout, &Entry::new(path.to_path_buf(), path.symlink_metadata()?))?;
+        } else if let Some(entries) = self::entries_list(arguments, stdout, stderr, status, path)? {
+            display.show_all(stdout, &entries)?;
+        }
+    }
+
+    stdout.flush()
+}
 
-    if arguments.sort_function == SortType::Size && arguments.hoist_function == HoistType::None {
-        arguments.hoist_function = HoistType::Directories;
+/// Displays which columns are enabled and how entries will be ordered, without listing anything.
+///
+/// # Errors
+///
+/// This function will return an error if the explanation fails to display.
+pub fn explain(arguments: &Arguments, stdout: &mut StdoutLock) -> Result<()> {
+    let columns = arguments.columns.iter().map(ToString::to_string).collect::<Box<[_]>>();
+    let sort = arguments.sort_functions.iter().map(ToString::to_string).collect::<Box<[_]>>();
+
+    cwrite!(arguments, bold; stdout, "Columns:")?;
+    writeln!(stdout, " {}", columns.join(", "))?;
+    cwrite!(arguments, bold; stdout, "Sort:")?;
+    write!(stdout, " {}", sort.join(", "))?;
+
+    if arguments.sort_reversed {
+        stdout.write_all(b" (reversed)")?;
     }
 
+    stdout.write_all(b"\n")?;
+
+    cwrite!(arguments, bold; stdout, "Hoist:")?;
+    writeln!(stdout, " {}", arguments.hoist_function)?;
+
+    stdout.flush()
+}
+
+/// The program's entry-point.
+///
+/// The returned exit code is non-zero if any path or entry could not be listed.
+///
+/// # Errors
+///
+/// This function will return an error if the program's execution fails in an unrecoverable manner.
+pub fn main() -> Result<ExitCode> {
+    let arguments = self::arguments::parse();
+
     let mut stdout = std::io::stdout().lock();
     let mut stderr = std::io::stderr().lock();
+    let mut status = Status::default();
+
+    if arguments.explain {
+        self::explain(&arguments, &mut stdout)?;
 
-    if arguments.paths.len() <= 1 {
+        return Ok(status.into());
+    }
+
+    #[cfg(feature = "watch")]
+    if arguments.watch {
+        return self::watch::watch(&arguments, &mut stdout, &mut stderr).map(Into::into);
+    }
+
+    self::list(&arguments, &mut stdout, &mut stderr, &mut status)?;
+
+    Ok(status.into())
+}
+
+/// Lists every path given within the program's arguments.
+///
+/// # Errors
+///
+/// This function will return an error if the listing could not be written.
+pub fn list(
+    arguments: &Arguments,
+    stdout: &mut StdoutLock,
+    stderr: &mut StderrLock,
+    status: &mut Status,
+) -> Result<()> {
+    // Watching lists repeatedly, and each listing should display every hard link's first occurrence again.
+    #[cfg(target_family = "unix")]
+    LISTED_HARDLINKS.with_borrow_mut(HashSet::clear);
+
+    if let Some(format) = arguments.tabular {
+        let display = TabularDisplay::new(arguments, format);
+
+        return self::show_tabular(arguments, &display, stdout, stderr, status);
+    }
+
+    if arguments.merge {
+        return self::show_merged(arguments, stdout, stderr, status);
+    }
+    if arguments.tree {
+        return self::show_trees(arguments, stdout, stderr, status);
+    }
+
+    if !arguments.recursive && arguments.paths.len() <= 1 && !arguments.paths.first().is_some_and(|path| path.is_file())
+    {
         let directory = arguments.paths.first().map_or_else(std::env::current_dir, |v| Ok(v.to_path_buf()))?;
-        let Some(entries) = self::entries_list(&arguments, &mut stdout, &mut stderr, directory)? else {
-            return stderr.flush();
+        let Some((entries, filtered)) = self::counted_entries_list(arguments, stdout, stderr, status, &directory)?
+        else {
+            stderr.flush()?;
+
+            return Ok(());
         };
 
-        self::show(&arguments, &mut stdout, entries)?;
+        if arguments.always_header {
+            self::show_header(arguments, stdout, stderr, status, &directory, entries.len())?;
+        } else {
+            self::show_git_status(arguments, stdout, &directory)?;
+        }
 
-        return stdout.flush();
+        let mut summary = self::show(arguments, stdout, entries)?;
+
+        summary.filtered = filtered;
+
+        if arguments.show_summary {
+            SummaryDisplay::new(arguments).show(stdout, &summary)?;
+
+            stdout.write_all(b"\n")?;
+        }
+
+        if arguments.grand_total {
+            self::show_grand_total(arguments, stdout, &summary)?;
+        }
+
+        stdout.flush()?;
+
+        return Ok(());
+    }
+
+    let summary_display = SummaryDisplay::new(arguments);
+    let mut total = Summary::default();
+    // Every path is listed in full, but a directory that's reached through several paths is only counted once.
+    let mut counted = VisitedDirectories::default();
+
+    // Recursive listings always display headers, so the current directory is listed through the same path.
+    let current_directory = [Path::new(".").into()];
+    let paths = if arguments.paths.is_empty() { &current_directory[..] } else { &arguments.paths[..] };
+
+    let (files, directories): (Vec<_>, Vec<_>) = paths.iter().partition(|path| path.is_file());
+
+    if !files.is_empty() {
+        total += self::show_files(arguments, stdout, stderr, status, &files)?;
+
+        if !directories.is_empty() {
+            stdout.write_all(b"\n")?;
+        }
     }
 
-    let header_display = HeaderDisplay::new(&arguments);
+    for (index, directory) in directories.iter().enumerate() {
+        if arguments.recursive {
+            total += self::show_recursive(arguments, stdout, stderr, status, &mut counted, directory)?;
+
+            if index < directories.len() - 1 {
+                stdout.write_all(b"\n")?;
+            }
+
+            continue;
+        }
 
-    for (index, directory) in arguments.paths.iter().enumerate() {
-        let Some(entries) = self::entries_list(&arguments, &mut stdout, &mut stderr, directory)? else {
+        let Some((entries, filtered)) = self::counted_entries_list(arguments, stdout, stderr, status, directory)?
+        else {
             stdout.flush()?;
             stderr.flush()?;
 
@@ -225,16 +1069,148 @@ pub fn main() -> Result<()> {
             continue;
         };
 
-        header_display.show(&mut stdout, &Entry::new(directory.to_path_buf(), directory.metadata()?))?;
+        self::show_header(arguments, stdout, stderr, status, directory, entries.len())?;
 
-        stdout.write_all(b"\n")?;
+        let mut summary = self::show(arguments, stdout, entries)?;
+
+        summary.filtered = filtered;
 
-        self::show(&arguments, &mut stdout, entries)?;
+        if arguments.show_summary {
+            summary_display.show(stdout, &summary)?;
+
+            stdout.write_all(b"\n")?;
+        }
+
+        if counted.visit(directory)? {
+            total += summary;
+        }
 
-        if index < arguments.paths.len() - 1 {
+        if index < directories.len() - 1 {
             stdout.write_all(b"\n")?;
         }
     }
 
+    if arguments.show_summary && (paths.len() > 1 || arguments.recursive) {
+        stdout.write_all(b"\n")?;
+
+        cwrite!(summary_display, bold; stdout, "total:")?;
+
+        stdout.write_all(b" ")?;
+
+        summary_display.show(stdout, &total)?;
+
+        stdout.write_all(b"\n")?;
+    }
+
+    if arguments.grand_total {
+        self::show_grand_total(arguments, stdout, &total)?;
+    }
+
+    stdout.flush()
+}
+
+/// Displays the given file paths as a single listing, returning a tally of the displayed entry types.
+///
+/// # Errors
+///
+/// This function will return an error if the listing could not be written.
+fn show_files(
+    arguments: &Arguments,
+    stdout: &mut StdoutLock,
+    stderr: &mut StderrLock,
+    status: &mut Status,
+    files: &[impl AsRef<Path>],
+) -> Result<Summary> {
+    let mut entries = Vec::with_capacity(files.len());
+
+    for path in files.iter().map(AsRef::as_ref) {
+        // Each path is handled separately, so that one failure doesn't prevent the remaining paths from listing.
+        match path.symlink_metadata() {
+            Ok(data) => entries.push(Entry::new(path.to_path_buf(), data)),
+            Err(error) => {
+                writeln!(stderr, "Failed to read '{}': {error}", path.to_string_lossy())?;
+
+                status.escalate(Status::Serious);
+            }
+        }
+    }
+
+    self::sort_entries(arguments, stderr, status, &mut entries);
+
+    let summary = self::show(arguments, stdout, entries)?;
+
+    if arguments.show_summary {
+        SummaryDisplay::new(arguments).show(stdout, &summary)?;
+
+        stdout.write_all(b"\n")?;
+    }
+
+    Ok(summary)
+}
+
+/// Displays the entries of every given path as a single sorted listing.
+///
+/// # Errors
+///
+/// This function will return an error if the listing could not be written.
+pub fn show_merged(
+    arguments: &Arguments,
+    stdout: &mut StdoutLock,
+    stderr: &mut StderrLock,
+    status: &mut Status,
+) -> Result<()> {
+    let current_directory = [Path::new(".").into()];
+    let paths = if arguments.paths.is_empty() { &current_directory[..] } else { &arguments.paths[..] };
+    let mut entries = Vec::new();
+
+    for path in paths {
+        if !path.is_file() {
+            if let Some(listed) = self::entries_list(arguments, stdout, stderr, status, path)? {
+                entries.extend(listed.into_vec());
+            }
+
+            continue;
+        }
+
+        match path.symlink_metadata() {
+            Ok(data) => entries.push(Entry::new(path.to_path_buf(), data)),
+            Err(error) => {
+                writeln!(stderr, "Failed to read '{}': {error}", path.to_string_lossy())?;
+
+                status.escalate(Status::Serious);
+            }
+        }
+    }
+
+    self::sort_entries(arguments, stderr, status, &mut entries);
+
+    let summary = self::show(arguments, stdout, entries)?;
+
+    if arguments.show_summary {
+        SummaryDisplay::new(arguments).show(stdout, &summary)?;
+
+        stdout.write_all(b"\n")?;
+    }
+    if arguments.grand_total {
+        self::show_grand_total(arguments, stdout, &summary)?;
+    }
+
     stdout.flush()
 }
+
+/// Displays the combined size of the files within the given summary.
+///
+/// # Errors
+///
+/// This function will return an error if the total could not be written.
+pub fn show_grand_total(arguments: &Arguments, stdout: &mut StdoutLock, total: &Summary) -> Result<()> {
+    stdout.write_all(b"\n")?;
+
+    cwrite!(arguments, bold; stdout, "grand total:")?;
+
+    stdout.write_all(b" ")?;
+
+    SizeDisplay::new(arguments).show_total(stdout, total.bytes)?;
+
+    stdout.write_all(b"\n")
+}
@@ -18,9 +18,14 @@
 extern crate alloc;
 
 use core::cmp::Ordering;
-use std::io::Result;
+use std::ffi::OsStr;
+use std::fs::Metadata;
+use std::io::{ErrorKind, Result};
+use std::sync::Once;
+use std::time::SystemTime;
 
-use crate::Entry;
+use crate::display::OwnerDisplay;
+use crate::{Entry, EntryKind};
 
 /// A type that sorts entries.
 pub trait Sorter {
@@ -30,39 +35,222 @@ pub trait Sorter {
     ///
     /// This function will return an error if sorting fails.
     fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering>;
+
+    /// Returns a sorter that sorts using this sorter, then breaks any ties using the given sorter.
+    ///
+    /// # Examples
+    ///
+    /// Doctests can't import the items of a binary crate, so this example isn't compiled.
+    ///
+    /// ```ignore
+    /// use crate::sort::{SortModified, SortName, SortSize, Sorter};
+    ///
+    /// // Sorts by size, then by last modified, then by name.
+    /// let sorter = SortSize.then(SortModified).then(SortName { case_sensitive: false });
+    /// ```
+    fn then<S: Sorter>(self, other: S) -> Chain<Self, S>
+    where
+        Self: Sized,
+    {
+        Chain { first: self, second: other }
+    }
+}
+
+impl<T: Sorter> Sorter for &T {
+    fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
+        <T as Sorter>::sort(self, a, b)
+    }
+}
+
+/// Sorts using one sorter, then breaks any ties using another.
+///
+/// This is usually created through [`Sorter::then`].
+///
+/// # Examples
+///
+/// As with [`Sorter::then`], this example isn't compiled.
+///
+/// ```ignore
+/// use crate::sort::{Chain, HoistType, SortType};
+///
+/// // Hoists directories, then sorts by extension within each group.
+/// let sorter = Chain::new(HoistType::Directories, SortType::Extension { extensionless_last: false });
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Chain<A, B> {
+    /// The sorter that is applied first.
+    pub first: A,
+    /// The sorter that is applied to entries that the first sorter considers equal.
+    pub second: B,
+}
+
+impl<A: Sorter, B: Sorter> Chain<A, B> {
+    /// Creates a new [`Chain`].
+    #[must_use]
+    pub const fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<A: Sorter, B: Sorter> Sorter for Chain<A, B> {
+    fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
+        let ordering = self.first.sort(a, b)?;
+
+        // The second sorter is only consulted when needed, as sorting may require reading metadata.
+        if ordering.is_ne() {
+            return Ok(ordering);
+        }
+
+        self.second.sort(a, b)
+    }
 }
 
 /// Sorting types.
-#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum SortType {
+    /// Do not sort, keeping the order that entries were read in.
+    None,
     /// Sort by name.
-    #[default]
-    Name,
+    Name {
+        /// Whether to distinguish between uppercase and lowercase characters.
+        case_sensitive: bool,
+    },
     /// Sort by size.
     Size,
     /// Sort by creation date.
     Created,
     /// Sort by last modified.
     Modified,
+    /// Sort by entry kind.
+    Type,
+    /// Sort by name length.
+    Length,
+    /// Sort by last accessed.
+    Accessed,
+    /// Sort by the selected timestamp.
+    Time(TimeField),
+    /// Sort by owner name.
+    Owner,
+    /// Sort by extension.
+    Extension {
+        /// Whether to place entries without an extension after those with one.
+        extensionless_last: bool,
+    },
+    /// Sort by permission bits.
+    Permissions,
+}
+
+impl core::fmt::Display for SortType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Name { case_sensitive: false } => f.write_str("name"),
+            Self::Name { case_sensitive: true } => f.write_str("name (case-sensitive)"),
+            Self::Size => f.write_str("size"),
+            Self::Created => f.write_str("created"),
+            Self::Modified => f.write_str("modified"),
+            Self::Type => f.write_str("type"),
+            Self::Length => f.write_str("length"),
+            Self::Accessed => f.write_str("accessed"),
+            Self::Time(field) => write!(f, "time ({field})"),
+            Self::Owner => f.write_str("owner"),
+            Self::Extension { extensionless_last: false } => f.write_str("extension"),
+            Self::Extension { extensionless_last: true } => f.write_str("extension (extensionless last)"),
+            Self::Permissions => f.write_str("permissions"),
+        }
+    }
+}
+
+impl SortType {
+    /// Returns whether this order naturally places the largest or most recent entries first.
+    #[must_use]
+    pub const fn is_descending(&self) -> bool {
+        matches!(self, Self::Size | Self::Created | Self::Modified | Self::Accessed | Self::Time(_))
+    }
+
+    /// Returns whether ties left by this order are broken by name when no other order follows it.
+    #[must_use]
+    pub const fn is_tied_by_name(&self) -> bool {
+        matches!(self, Self::Length | Self::Owner | Self::Permissions)
+    }
+}
+
+impl Default for SortType {
+    fn default() -> Self {
+        Self::Name { case_sensitive: false }
+    }
 }
 
 impl Sorter for SortType {
     fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
         match self {
-            Self::Name => SortName.sort(a, b),
+            Self::None => Ok(Ordering::Equal),
+            Self::Name { case_sensitive } => SortName { case_sensitive: *case_sensitive }.sort(a, b),
             Self::Size => SortSize.sort(a, b),
-            Self::Created => SortCreated.sort(a, b),
-            Self::Modified => SortModified.sort(a, b),
+            Self::Created | Self::Time(TimeField::Created) => SortCreated.sort(a, b),
+            Self::Modified | Self::Time(TimeField::Modified) => SortModified.sort(a, b),
+            Self::Accessed | Self::Time(TimeField::Accessed) => SortAccessed.sort(a, b),
+            Self::Type => SortKind.sort(a, b),
+            Self::Length => SortLength.sort(a, b),
+            Self::Owner => SortOwner.sort(a, b),
+            Self::Extension { extensionless_last } => {
+                SortExtension { extensionless_last: *extensionless_last }.sort(a, b)
+            }
+            Self::Permissions => SortPermissions.sort(a, b),
+        }
+    }
+}
+
+/// The timestamps that entries may be displayed and sorted by.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TimeField {
+    /// The last modification date.
+    #[default]
+    Modified,
+    /// The creation date.
+    Created,
+    /// The last access date.
+    Accessed,
+}
+
+impl TimeField {
+    /// Returns this timestamp from the given metadata.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the timestamp is unavailable.
+    pub fn get(self, data: &Metadata) -> Result<SystemTime> {
+        match self {
+            Self::Modified => data.modified(),
+            Self::Created => data.created(),
+            Self::Accessed => data.accessed(),
         }
     }
 }
 
+impl core::fmt::Display for TimeField {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Modified => "modified",
+            Self::Created => "created",
+            Self::Accessed => "accessed",
+        })
+    }
+}
+
 /// Sort by name.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-pub struct SortName;
+pub struct SortName {
+    /// Whether to distinguish between uppercase and lowercase characters.
+    pub case_sensitive: bool,
+}
 
 impl Sorter for SortName {
     fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
+        if self.case_sensitive {
+            return Ok(a.path.as_os_str().cmp(b.path.as_os_str()));
+        }
+
         let a_path = a.path.as_os_str().to_ascii_lowercase();
         let b_path = b.path.as_os_str().to_ascii_lowercase();
 
@@ -81,15 +269,29 @@ impl Sorter for SortSize {
 }
 
 /// Sort by creation date.
+///
+/// Falls back to sorting by last modified if the file system does not record creation dates.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct SortCreated;
 
 impl Sorter for SortCreated {
     fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
-        let a_time = a.data.created()?;
-        let b_time = b.data.created()?;
+        /// Ensures that the fallback is only reported once, rather than for every comparison.
+        static FALLBACK_WARNING: Once = Once::new();
 
-        Ok(a_time.cmp(&b_time).reverse())
+        match (a.data.created(), b.data.created()) {
+            (Ok(a_time), Ok(b_time)) => Ok(a_time.cmp(&b_time).reverse()),
+            (Err(error), _) | (_, Err(error)) if error.kind() == ErrorKind::Unsupported => {
+                FALLBACK_WARNING.call_once(|| {
+                    eprintln!(
+                        "Creation dates are unavailable on this file system, sorting by modification date instead."
+                    );
+                });
+
+                SortModified.sort(a, b)
+            }
+            (Err(error), _) | (_, Err(error)) => Err(error),
+        }
     }
 }
 
@@ -106,6 +308,98 @@ impl Sorter for SortModified {
     }
 }
 
+/// Sort by last accessed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SortAccessed;
+
+impl Sorter for SortAccessed {
+    fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
+        let a_time = a.data.accessed()?;
+        let b_time = b.data.accessed()?;
+
+        Ok(a_time.cmp(&b_time).reverse())
+    }
+}
+
+/// Sort by entry kind.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SortKind;
+
+impl Sorter for SortKind {
+    fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
+        Ok(a.kind().cmp(&b.kind()))
+    }
+}
+
+/// Sort by name length.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SortLength;
+
+impl Sorter for SortLength {
+    fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
+        let length = |entry: &Entry| entry.path.file_name().map_or(0, |name| name.to_string_lossy().chars().count());
+
+        Ok(length(a).cmp(&length(b)))
+    }
+}
+
+/// Sort by owner name.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SortOwner;
+
+impl Sorter for SortOwner {
+    fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
+        Ok(OwnerDisplay::get_owner_name(a)?.cmp(&OwnerDisplay::get_owner_name(b)?))
+    }
+}
+
+/// Sort by permission bits numerically.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SortPermissions;
+
+impl SortPermissions {
+    /// Returns the permission bits of the given entry, excluding its file type.
+    #[cfg(target_family = "unix")]
+    fn get_bits(entry: &Entry) -> u32 {
+        use std::os::unix::fs::MetadataExt;
+
+        entry.data.mode() & 0o7777
+    }
+
+    /// Returns the attribute bits of the given entry.
+    #[cfg(target_family = "windows")]
+    fn get_bits(entry: &Entry) -> u32 {
+        use std::os::windows::fs::MetadataExt;
+
+        entry.data.file_attributes()
+    }
+}
+
+impl Sorter for SortPermissions {
+    fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
+        Ok(Self::get_bits(a).cmp(&Self::get_bits(b)))
+    }
+}
+
+/// Sort by extension, placing entries without one first by default.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SortExtension {
+    /// Whether to place entries without an extension after those with one.
+    pub extensionless_last: bool,
+}
+
+impl Sorter for SortExtension {
+    fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
+        let extension = |entry: &Entry| entry.path.extension().map(OsStr::to_ascii_lowercase);
+
+        match (extension(a), extension(b)) {
+            (None, Some(_)) if self.extensionless_last => Ok(Ordering::Greater),
+            (Some(_), None) if self.extensionless_last => Ok(Ordering::Less),
+            (a, b) => Ok(a.cmp(&b)),
+        }
+    }
+}
+
 /// Hoisting types.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub enum HoistType {
@@ -118,6 +412,26 @@ pub enum HoistType {
     Hidden,
     /// Hoist symbolic links.
     Symlinks,
+    /// Hoist executable files.
+    Executables,
+    /// Hoist files, placing directories last.
+    Files,
+    /// Hoist entries with a specific extension.
+    Extension(HoistExtension),
+}
+
+impl core::fmt::Display for HoistType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::None => f.write_str("none"),
+            Self::Directories => f.write_str("directories"),
+            Self::Hidden => f.write_str("hidden"),
+            Self::Symlinks => f.write_str("symlinks"),
+            Self::Executables => f.write_str("executables"),
+            Self::Files => f.write_str("files"),
+            Self::Extension(HoistExtension { extension }) => write!(f, "ext:{extension}"),
+        }
+    }
 }
 
 impl Sorter for HoistType {
@@ -127,6 +441,9 @@ impl Sorter for HoistType {
             Self::Directories => HoistDirectories.sort(a, b),
             Self::Hidden => HoistHidden.sort(a, b),
             Self::Symlinks => HoistSymlinks.sort(a, b),
+            Self::Executables => HoistExecutables.sort(a, b),
+            Self::Files => HoistFiles.sort(a, b),
+            Self::Extension(hoist) => hoist.sort(a, b),
         }
     }
 }
@@ -175,3 +492,113 @@ impl Sorter for HoistSymlinks {
         }
     }
 }
+
+/// Sort directories later.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HoistFiles;
+
+impl Sorter for HoistFiles {
+    fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
+        HoistDirectories.sort(a, b).map(Ordering::reverse)
+    }
+}
+
+/// Sort executable files earlier.
+///
+/// Directories are never considered executable, despite usually having their execute bit set.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct HoistExecutables;
+
+impl Sorter for HoistExecutables {
+    fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
+        match (a.kind() == EntryKind::Executable, b.kind() == EntryKind::Executable) {
+            (true, false) => Ok(Ordering::Less),
+            (false, true) => Ok(Ordering::Greater),
+            _ => Ok(Ordering::Equal),
+        }
+    }
+}
+
+/// Sort entries with a specific extension earlier.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HoistExtension {
+    /// The extension to hoist, without a leading dot.
+    pub extension: Box<str>,
+}
+
+impl Sorter for HoistExtension {
+    fn sort(&self, a: &Entry, b: &Entry) -> Result<Ordering> {
+        let matches = |entry: &Entry| entry.path.extension().is_some_and(|v| *v == *self.extension);
+
+        match (matches(a), matches(b)) {
+            (true, false) => Ok(Ordering::Less),
+            (false, true) => Ok(Ordering::Greater),
+            _ => Ok(Ordering::Equal),
+        }
+    }
+}
+
+/// Grouping types.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GroupType {
+    /// Don't group anything.
+    #[default]
+    None,
+    /// Group entries into sections by their kind.
+    Type,
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cmp::Ordering;
+    use std::io::{Error, Result};
+
+    use super::{Chain, SortName, SortSize, Sorter};
+    use crate::Entry;
+
+    /// A sorter that always fails, showing whether it was consulted.
+    struct SortFailing;
+
+    impl Sorter for SortFailing {
+        fn sort(&self, _: &Entry, _: &Entry) -> Result<Ordering> {
+            Err(Error::other("consulted"))
+        }
+    }
+
+    /// Creates files with the given names and sizes, returning an entry for each.
+    fn entries(files: &[(&str, usize)]) -> Result<Box<[Entry]>> {
+        let directory = std::env::temp_dir().join(format!("rs-sort-{}", std::process::id()));
+
+        std::fs::create_dir_all(&directory)?;
+
+        let entries = files.iter().map(|(name, size)| {
+            let path = directory.join(name);
+
+            std::fs::write(&path, vec![0; *size])?;
+
+            Ok(Entry::new(path.clone(), path.metadata()?))
+        });
+        let entries = entries.collect::<Result<Box<[_]>>>();
+
+        std::fs::remove_dir_all(&directory)?;
+
+        entries
+    }
+
+    #[test]
+    fn chain_only_breaks_ties() -> Result<()> {
+        let [small, large, other] = &*entries(&[("a", 1), ("b", 2), ("c", 2)])? else { unreachable!() };
+        let chain = Chain::new(SortSize, SortName::default());
+
+        assert_eq!(chain.sort(small, large)?, Ordering::Greater);
+        assert_eq!(chain.sort(large, other)?, Ordering::Less);
+        assert_eq!(chain.sort(other, large)?, Ordering::Greater);
+
+        let chain = SortSize.then(SortFailing);
+
+        assert_eq!(chain.sort(small, large)?, Ordering::Greater);
+        assert!(chain.sort(large, other).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+use core::time::Duration;
+use std::io::{Error, Result, StderrLock, StdoutLock, Write};
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+
+use notify::{EventKind, RecursiveMode, Watcher};
+
+use crate::arguments::Arguments;
+use crate::Status;
+
+/// The escape sequence that clears the terminal and moves the cursor to its top-left corner.
+const CLEAR_SCREEN: &[u8] = b"\x1b[2J\x1b[H";
+/// How long to wait for further changes before re-displaying the listing.
+///
+/// A single operation, such as saving a file, often causes several changes in quick succession.
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(100);
+
+/// A message sent to the watching thread.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Message {
+    /// A listed directory was changed.
+    Changed,
+    /// The program was interrupted.
+    Interrupted,
+}
+
+/// Displays the listing, then re-displays it whenever the listed directories change, until interrupted.
+///
+/// Returns the status of the most recent listing.
+///
+/// # Errors
+///
+/// This function will return an error if the directories could not be watched or if the listing could not be written.
+pub fn watch(arguments: &Arguments, stdout: &mut StdoutLock, stderr: &mut StderrLock) -> Result<Status> {
+    let (sender, receiver) = mpsc::channel();
+    let interrupt_sender = sender.clone();
+
+    // The receiver is only dropped once watching has stopped, so failing to send a message is harmless.
+    ctrlc::set_handler(move || _ = interrupt_sender.send(Message::Interrupted)).map_err(Error::other)?;
+
+    let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        // Reading a directory may itself be reported as an access, which would otherwise re-display forever.
+        if event.is_ok_and(|event| !matches!(event.kind, EventKind::Access(_))) {
+            _ = sender.send(Message::Changed);
+        }
+    })
+    .map_err(Error::other)?;
+
+    let mode = if arguments.recursive || arguments.tree {
+        RecursiveMode::Recursive
+    } else {
+        RecursiveMode::NonRecursive
+    };
+
+    if arguments.paths.is_empty() {
+        watcher.watch(Path::new("."), mode).map_err(Error::other)?;
+    } else {
+        for path in &arguments.paths {
+            watcher.watch(path, mode).map_err(Error::other)?;
+        }
+    }
+
+    loop {
+        let mut status = Status::default();
+
+        stdout.write_all(CLEAR_SCREEN)?;
+
+        crate::list(arguments, stdout, stderr, &mut status)?;
+
+        stdout.flush()?;
+        stderr.flush()?;
+
+        if receiver.recv() != Ok(Message::Changed) {
+            return Ok(status);
+        }
+
+        loop {
+            match receiver.recv_timeout(DEBOUNCE_DELAY) {
+                Ok(Message::Changed) => {}
+                Ok(Message::Interrupted) | Err(RecvTimeoutError::Disconnected) => return Ok(status),
+                Err(RecvTimeoutError::Timeout) => break,
+            }
+        }
+    }
+}
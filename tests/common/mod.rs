@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+//! Utilities shared between tests.
+
+use std::fs;
+use std::path::PathBuf;
+
+/// Returns a new, empty directory for the named test.
+pub fn test_directory(name: &str) -> std::io::Result<PathBuf> {
+    let path = std::env::temp_dir().join(format!("rs-{name}-{}", std::process::id()));
+
+    if path.exists() {
+        fs::remove_dir_all(&path)?;
+    }
+
+    fs::create_dir_all(&path)?;
+
+    Ok(path)
+}
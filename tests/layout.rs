@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+//! Tests for the layouts that entries are displayed in.
+
+use std::fs;
+use std::process::Command;
+
+use self::common::test_directory;
+
+mod common;
+
+#[test]
+fn reversed_grids() -> std::io::Result<()> {
+    let root = test_directory("reversed-grids")?;
+
+    for name in ["a", "b", "c", "d", "e"] {
+        fs::write(root.join(name), [])?;
+    }
+
+    let list = |format: &str| {
+        Command::new(env!("CARGO_BIN_EXE_rs"))
+            .env("RS_TEST_WIDTH", "10")
+            .args(["--color=never", "--reverse", "--format", format])
+            .arg(&root)
+            .output()
+    };
+    let down = list("grid")?;
+    let across = list("across")?;
+
+    fs::remove_dir_all(&root)?;
+
+    // The last entry of the unreversed listing is placed in the top-left corner, then the grid is filled as usual.
+    assert_eq!(String::from_utf8_lossy(&down.stdout), "e  c  a\nd  b\n");
+    assert_eq!(String::from_utf8_lossy(&across.stdout), "e  d  c\nb  a\n");
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+//! Tests for listing the paths given as arguments.
+#![cfg(target_family = "unix")]
+
+use std::fs;
+use std::process::Command;
+
+use self::common::test_directory;
+
+mod common;
+
+#[test]
+fn unlistable_paths_are_skipped() -> std::io::Result<()> {
+    let root = test_directory("paths")?;
+
+    fs::create_dir(root.join("directory"))?;
+    fs::write(root.join("directory").join("file"), [])?;
+
+    let created = Command::new("mkfifo").arg(root.join("pipe")).status()?;
+    let output = Command::new(env!("CARGO_BIN_EXE_rs"))
+        .arg("--color=never")
+        .args([root.join("pipe"), root.join("directory")])
+        .output()?;
+
+    fs::remove_dir_all(&root)?;
+
+    assert!(created.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(stdout.contains("file"), "the directory after the pipe should be listed: {stdout}");
+    assert!(stderr.contains("Failed to read"), "the pipe should be reported: {stderr}");
+
+    Ok(())
+}
+
+#[test]
+fn single_paths_display_a_grand_total() -> std::io::Result<()> {
+    let root = test_directory("single")?;
+
+    fs::write(root.join("file"), [0; 10])?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rs"))
+        .args(["--grand-total", "--color=never"])
+        .arg(&root)
+        .output()?;
+
+    fs::remove_dir_all(&root)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert!(stdout.ends_with("grand total: 10\n"), "the total should follow a single listing: {stdout}");
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+//! Tests for the output of `--porcelain`.
+#![cfg(target_family = "unix")]
+
+use std::fs::{self, Permissions};
+use std::os::unix::fs::{symlink, PermissionsExt};
+use std::process::Command;
+
+use self::common::test_directory;
+
+mod common;
+
+#[test]
+fn porcelain_never_contains_escapes() -> std::io::Result<()> {
+    let root = test_directory("porcelain")?;
+
+    fs::create_dir(root.join("directory"))?;
+    fs::write(root.join("directory").join("file"), [])?;
+    fs::write(root.join("executable"), [])?;
+    fs::set_permissions(root.join("executable"), Permissions::from_mode(0o755))?;
+    symlink("executable", root.join("link"))?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rs"))
+        .env("RS_TEST_COLOR", "always")
+        .args(["--porcelain", "--color=always", "--recursive", "--group-by", "type", "--summary"])
+        .arg(&root)
+        .output()?;
+
+    fs::remove_dir_all(&root)?;
+
+    assert!(output.status.success());
+    assert!(!output.stdout.is_empty());
+    assert!(!output.stdout.contains(&0x1B), "{}", String::from_utf8_lossy(&output.stdout).escape_debug());
+
+    Ok(())
+}
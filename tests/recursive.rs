@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: AGPL-3.0-or-later
+//
+// Copyright © 2024 Jaxydog
+//
+// This file is part of rs.
+//
+// rs is free software: you can redistribute it and/or modify it under the terms of the GNU Affero
+// General Public License as published by the Free Software Foundation, either version 3 of the
+// License, or (at your option) any later version.
+//
+// rs is distributed in the hope that it will be useful, but WITHOUT ANY WARRANTY; without even the
+// implied warranty of MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the GNU Affero
+// General Public License for more details.
+//
+// You should have received a copy of the GNU Affero General Public License along with rs. If not,
+// see <https://www.gnu.org/licenses/>.
+
+//! Tests for listing directories recursively.
+#![cfg(target_family = "unix")]
+
+use std::fs::{self, Permissions};
+use std::os::unix::fs::{symlink, PermissionsExt};
+use std::path::Path;
+use std::process::{Command, Output};
+
+use self::common::test_directory;
+
+mod common;
+
+/// Creates a directory containing an unlistable directory 'a', followed by 'b', which links back to its parent.
+///
+/// Returns whether 'a' is actually unreadable, as privileged users can read the directory regardless.
+fn create_entries(root: &Path) -> std::io::Result<bool> {
+    fs::create_dir(root.join("a"))?;
+    fs::create_dir(root.join("b"))?;
+    fs::write(root.join("b").join("file"), [])?;
+    symlink("..", root.join("b").join("self"))?;
+    fs::set_permissions(root.join("a"), Permissions::from_mode(0o000))?;
+
+    Ok(fs::read_dir(root.join("a")).is_err())
+}
+
+/// Lists the given directory using the given arguments, then removes the directory.
+fn list_and_remove(root: &Path, arguments: &[&str]) -> std::io::Result<Output> {
+    let output = Command::new(env!("CARGO_BIN_EXE_rs")).args(arguments).arg(root).output()?;
+
+    fs::set_permissions(root.join("a"), Permissions::from_mode(0o755))?;
+    fs::remove_dir_all(root)?;
+
+    Ok(output)
+}
+
+#[test]
+fn recursion_continues_past_unlistable_directories() -> std::io::Result<()> {
+    let root = test_directory("recursive")?;
+    let is_unreadable = self::create_entries(&root)?;
+    let output = self::list_and_remove(&root, &["--recursive", "--follow-symlinks", "--color=never"])?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout.contains("file"), "the directory after 'a' should be listed: {stdout}");
+    assert!(stderr.contains("Not listing already listed directory"), "the cycle should be reported: {stderr}");
+
+    if is_unreadable {
+        assert!(stderr.contains("Failed to read"), "'a' should be reported: {stderr}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn tree_continues_past_unlistable_directories() -> std::io::Result<()> {
+    let root = test_directory("tree")?;
+    let is_unreadable = self::create_entries(&root)?;
+    let output = self::list_and_remove(&root, &["--tree", "--follow-symlinks", "--color=never"])?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stdout.contains("a/"), "the unlistable directory should still be displayed: {stdout}");
+    assert!(stdout.contains("file"), "the directory after 'a' should be listed: {stdout}");
+    assert!(stderr.contains("Not listing already listed directory"), "the cycle should be reported: {stderr}");
+
+    if is_unreadable {
+        assert!(stderr.contains("Failed to read"), "'a' should be reported: {stderr}");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn overlapping_paths_are_counted_once() -> std::io::Result<()> {
+    let root = test_directory("overlapping")?;
+
+    fs::create_dir_all(root.join("a").join("b"))?;
+    fs::write(root.join("a").join("b").join("file"), [0; 10])?;
+
+    let output = Command::new(env!("CARGO_BIN_EXE_rs"))
+        .args(["--recursive", "--grand-total", "--color=never"])
+        .args([root.join("a"), root.join("a").join("b")])
+        .output()?;
+
+    fs::remove_dir_all(&root)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(stdout.matches("file").count(), 2, "both paths should be listed in full: {stdout}");
+    assert!(stdout.ends_with("grand total: 10\n"), "the file should only be counted once: {stdout}");
+
+    Ok(())
+}